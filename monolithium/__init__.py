@@ -13,6 +13,20 @@ class Paths:
     REPO:    Path = (PACKAGE.parent)
     BUILD:   Path = (Path.cwd()/"build")
 
+# Cargo features that are also flags of the search command
+RUNTIME: set[str] = {
+    "most-octaves",
+    "skip-rejection",
+    "checked-rejection",
+    "linear-fade",
+    "linear-fill",
+    "only-hill",
+    "filter-fracts",
+    "depth-fracts",
+    "state-seed",
+    "sister-perlin",
+}
+
 # ---------------------------------------------------------------------------- #
 
 def rustlith(
@@ -38,11 +52,18 @@ def rustlith(
     cargo = tomllib.loads((Paths.PACKAGE/"Cargo.toml").read_text(encoding="utf-8"))
     features = list()
 
-    for feature in cargo["features"]:
-        if (flag := f"--{feature}") in args:
-            features.append("--features")
-            features.append(feature)
-            args.remove(flag)
+    for feature, group in cargo["features"].items():
+        if (flag := f"--{feature}") not in args:
+            continue
+        args.remove(flag)
+
+        # Strategies are runtime flags of the search, no need to recompile
+        for feature in (group or [feature]):
+            if (feature in RUNTIME) and ("search" in args):
+                args.insert(args.index("search") + 1, f"--{feature}")
+            else:
+                features.append("--features")
+                features.append(feature)
 
     os.environ.update(RUSTFLAGS="-C target-cpu=native")
 
@@ -0,0 +1,49 @@
+use monolithium::*;
+use std::hint::black_box;
+use std::time::Instant;
+
+/// Seeds per measurement
+const SEEDS: u64 = 200_000;
+
+/// Nanoseconds per seed of a closure over many seeds
+fn measure(name: &str, mut work: impl FnMut(u64)) {
+    let start = Instant::now();
+    for seed in 0..SEEDS {
+        work(black_box(seed));
+    }
+    let nanos = start.elapsed().as_nanos() as f64 / SEEDS as f64;
+    println!("{name:<24} {nanos:>9.1} ns/seed");
+}
+
+fn main() {
+    for rejection in [Rejection::Exact, Rejection::Checked, Rejection::Skip] {
+        println!("{rejection:?}");
+
+        measure("discard skipped", |seed| {
+            let mut rng = JavaRNG::from_seed(seed);
+            Perlin::discard(&mut rng, SKIP_OCTAVES, rejection);
+            black_box(rng);
+        });
+
+        measure("shuffle octave", |seed| {
+            let mut rng = JavaRNG::from_seed(seed);
+            black_box(Perlin::from_rng(&mut rng, rejection));
+        });
+
+        let mut lanes: [World; 8] = std::array::from_fn(|_| World::with(Strategy {
+            skip_rejection: rejection == Rejection::Skip,
+            checked_rejection: rejection == Rejection::Checked,
+            ..Default::default()
+        }).unwrap());
+        let mut seeds = [0; 8];
+        measure("init lanes and probe", |seed| {
+            seeds[(seed % 8) as usize] = seed;
+            if seed % 8 == 7 {
+                World::init_lanes(&mut lanes, seeds);
+                for world in &lanes {
+                    black_box(world.is_monolith(0, 0));
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,360 @@
+use crate::*;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::ops::Range;
+use std::sync::mpsc;
+use std::time::Duration;
+use std::time::Instant;
+
+/// The seeds a coordinator searches, which its workers must agree on
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Setup {
+    /// Seed of the shared random generator, if any
+    pub seed: Option<Seed>,
+
+    /// Seeds of the factory in total
+    pub total: u64,
+
+    /// Which seeds the factory makes, see `SeedFactory::identity()`
+    #[serde(default)]
+    pub seeds: String,
+
+    /// Seeds of each work block
+    pub chunks: u64,
+
+    /// Part of the seeds searched
+    pub shard: Shard,
+
+    /// Tradeoffs the seeds are searched with
+    #[serde(default)]
+    pub strategy: Strategy,
+
+    /// Cutoffs of the seeds skipped with `--filter-fracts`
+    #[serde(default)]
+    pub fracts: FractFilter,
+
+    /// Where and how monoliths are searched in each seed
+    #[serde(default)]
+    pub options: FindOptions,
+}
+
+/// A json line between a coordinator and its workers
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+pub enum Message {
+    /// The seeds to search, sent once to each worker
+    Setup(Setup),
+
+    /// Work blocks to search next
+    Work {start: u64, end: u64},
+
+    /// A monolith of the current work
+    Found(Monolith),
+
+    /// All monoliths of some work were sent
+    Done {start: u64, end: u64},
+
+    /// Nothing is left to do
+    Stop,
+}
+
+impl Message {
+    fn send(&self, stream: &mut impl Write) -> std::io::Result<()> {
+        writeln!(stream, "{}", serde_json::to_string(self).unwrap())?;
+        stream.flush()
+    }
+
+    fn receive(reader: &mut impl BufRead) -> std::io::Result<Self> {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        serde_json::from_str(&line).map_err(std::io::Error::other)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Ranges of work blocks yet to be done, and which connections have them
+struct Ledger {
+    pending: Vec<Range<u64>>,
+    running: Vec<(Range<u64>, Instant, usize)>,
+    left: usize,
+}
+
+impl Ledger {
+
+    /// The next range to work on, or one a worker took too long with. None
+    /// when all are done, waiting while others may still finish theirs
+    fn take(this: &Mutex<Self>, timeout: Duration, owner: usize) -> Option<Range<u64>> {
+        loop {
+            let mut ledger = this.lock().unwrap();
+            if ledger.left == 0 {
+                return None;
+            }
+
+            if let Some(range) = ledger.pending.pop() {
+                ledger.running.push((range.clone(), Instant::now(), owner));
+                return Some(range);
+            }
+
+            // Reassign stale work, whoever sends it first wins
+            if let Some(range) = ledger.running.iter()
+                .find(|(_, since, _)| since.elapsed() > timeout)
+                .map(|(range, _, _)| range.clone())
+            {
+                for (other, since, _) in ledger.running.iter_mut() {
+                    if *other == range {
+                        *since = Instant::now();
+                    }
+                }
+                ledger.running.push((range.clone(), Instant::now(), owner));
+                return Some(range);
+            }
+
+            drop(ledger);
+            std::thread::sleep(Duration::from_millis(100));
+        }
+    }
+
+    /// Whether a range wasn't done before by someone else
+    fn finish(&mut self, range: &Range<u64>) -> bool {
+        let before = self.running.len();
+        self.running.retain(|(other, _, _)| other != range);
+        if self.running.len() == before {
+            return false;
+        }
+        self.left -= 1;
+        true
+    }
+
+    /// Hand a range of some owner back, to be done by someone else
+    /// unless it was already reassigned to them
+    fn abandon(&mut self, range: Range<u64>, owner: usize) {
+        let Some(index) = self.running.iter().position(|(other, _, by)| *other == range && *by == owner) else {
+            return;
+        };
+        self.running.swap_remove(index);
+        if !self.running.iter().any(|(other, _, _)| *other == range) {
+            self.pending.push(range);
+        }
+    }
+}
+
+/// Hand out ranges of `size` work blocks to workers connecting to a listener,
+/// calling `done` with the monoliths of each range exactly once, in any order
+pub fn coordinate(
+    listener: TcpListener,
+    setup: Setup,
+    blocks: Range<u64>,
+    size: u64,
+    timeout: Duration,
+    mut done: impl FnMut(Range<u64>, Vec<Monolith>),
+) {
+    // Popped from the back, the first ones first
+    let size = size.max(1);
+    let pending: Vec<Range<u64>> = (0..(blocks.end - blocks.start).div_ceil(size)).rev()
+        .map(|n| (blocks.start + n*size)..(blocks.start + (n + 1)*size).min(blocks.end))
+        .collect();
+
+    let ledger = Arc::new(Mutex::new(Ledger {
+        left: pending.len(),
+        running: Vec::new(),
+        pending,
+    }));
+
+    let setup = serde_json::to_string(&Message::Setup(setup)).unwrap();
+    let (sender, results) = mpsc::channel();
+
+    // Each worker talks to its own thread
+    let (accepting, handing) = (ledger.clone(), setup.clone());
+    std::thread::spawn(move || {
+        for (owner, stream) in listener.incoming().flatten().enumerate() {
+            let (ledger, setup, sender) = (accepting.clone(), handing.clone(), sender.clone());
+            std::thread::spawn(move || {
+                let _ = serve(stream, owner, &setup, &ledger, timeout, &sender);
+            });
+        }
+    });
+
+    while ledger.lock().unwrap().left > 0 {
+        if let Ok((range, monoliths)) = results.recv_timeout(Duration::from_millis(100)) {
+            done(range, monoliths);
+        }
+    }
+
+    // Ranges finished right at the end
+    while let Ok((range, monoliths)) = results.try_recv() {
+        done(range, monoliths);
+    }
+}
+
+/// Give work to a single worker until nothing is left, waiting on each
+/// range for as long as it takes, as others may redo it after `timeout`
+fn serve(
+    stream: TcpStream,
+    owner: usize,
+    setup: &str,
+    ledger: &Mutex<Ledger>,
+    timeout: Duration,
+    results: &mpsc::Sender<(Range<u64>, Vec<Monolith>)>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut stream = stream;
+    writeln!(stream, "{setup}")?;
+
+    while let Some(range) = Ledger::take(ledger, timeout, owner) {
+        let work = Message::Work {start: range.start, end: range.end};
+        let mut found = Vec::new();
+
+        let outcome = work.send(&mut stream).and_then(|_| loop {
+            match Message::receive(&mut reader)? {
+                Message::Found(mono) => found.push(mono),
+                Message::Done {start, end} if (start..end) == range => break Ok(()),
+                other => break Err(std::io::Error::other(format!("Unexpected {other:?}"))),
+            }
+        });
+
+        if let Err(error) = outcome {
+            ledger.lock().unwrap().abandon(range, owner);
+            return Err(error);
+        }
+
+        // The results must be handed before the last range counts as done
+        let mut ledger = ledger.lock().unwrap();
+        if ledger.finish(&range) {
+            results.send((range, found)).unwrap();
+        }
+    }
+
+    Message::Stop.send(&mut stream)
+}
+
+/// A connection to a coordinator, searching what it hands out
+pub struct Worker {
+    stream: TcpStream,
+    reader: BufReader<TcpStream>,
+}
+
+impl Worker {
+
+    /// Connect to a coordinator, learning the seeds it searches
+    pub fn connect(address: &str) -> (Self, Setup) {
+        let stream = TcpStream::connect(address)
+            .expect("Could not connect to coordinator");
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        match Message::receive(&mut reader).expect("Lost the coordinator") {
+            Message::Setup(setup) => (Self {stream, reader}, setup),
+            other => panic!("Unexpected {other:?} from coordinator"),
+        }
+    }
+
+    /// Call `search` on each range of work blocks handed out, until it stops
+    /// or goes away, as it does once all is done
+    pub fn work(mut self, mut search: impl FnMut(Range<u64>) -> Vec<Monolith>) {
+        while let Ok(message) = Message::receive(&mut self.reader) {
+            match message {
+                Message::Work {start, end} => {
+                    let sent = search(start..end).into_iter()
+                        .map(Message::Found)
+                        .chain([Message::Done {start, end}])
+                        .try_for_each(|message| message.send(&mut self.stream));
+                    if sent.is_err() {
+                        return;
+                    }
+                },
+                Message::Stop => return,
+                other => panic!("Unexpected {other:?} from coordinator"),
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every range is done once by some worker, also when one vanishes
+    #[test]
+    fn cluster() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let setup = Setup {
+            seed: Some(617), total: 100, seeds: "linear 0 100".into(), chunks: 1,
+            shard: Shard::default(), strategy: Strategy::default(),
+            fracts: FractFilter::default(), options: FindOptions::default().step(64),
+        };
+        let mono = |seed| serde_json::from_str::<Monolith>(&format!(
+            r#"{{"area":{seed},"seed":{seed},"minx":0,"maxx":0,"minz":0,"maxz":0}}"#)).unwrap();
+
+        let workers: Vec<_> = (0..3).map(|worker| {
+            let address = address.clone();
+            std::thread::spawn(move || {
+
+                // A worker that disconnects with its first range undone
+                if worker == 0 {
+                    let stream = TcpStream::connect(&address).unwrap();
+                    let mut reader = BufReader::new(stream);
+                    Message::receive(&mut reader).unwrap();
+                    Message::receive(&mut reader).unwrap();
+                    return;
+                }
+
+                let (worker, setup) = Worker::connect(&address);
+                assert_eq!((setup.seed, setup.total), (Some(617), 100));
+                assert_eq!(setup.options, FindOptions::default().step(64));
+                worker.work(|range| range.map(mono).collect());
+            })
+        }).collect();
+
+        let mut seen = Vec::new();
+        coordinate(listener, setup, 0..100, 7, Duration::from_secs(5), |range, monoliths| {
+            assert_eq!(monoliths.iter().map(|mono| mono.seed).collect::<Vec<_>>(), range.collect::<Vec<_>>());
+            seen.extend(monoliths.into_iter().map(|mono| mono.seed));
+        });
+
+        seen.sort();
+        assert_eq!(seen, (0..100).collect::<Vec<_>>());
+        workers.into_iter().for_each(|worker| worker.join().unwrap());
+    }
+
+    /// Ranges taking longer than the timeout are still done, once
+    #[test]
+    fn slow() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let setup = Setup {
+            seed: None, total: 4, seeds: "linear 0 4".into(), chunks: 1,
+            shard: Shard::default(), strategy: Strategy::default(),
+            fracts: FractFilter::default(), options: FindOptions::default(),
+        };
+        let mono = |seed| serde_json::from_str::<Monolith>(&format!(
+            r#"{{"area":{seed},"seed":{seed},"minx":0,"maxx":0,"minz":0,"maxz":0}}"#)).unwrap();
+
+        let workers: Vec<_> = (0..2).map(|_| {
+            let address = address.clone();
+            std::thread::spawn(move || {
+                let (worker, _) = Worker::connect(&address);
+                worker.work(|range| {
+                    std::thread::sleep(Duration::from_millis(300));
+                    range.map(mono).collect()
+                });
+            })
+        }).collect();
+
+        let mut seen = Vec::new();
+        coordinate(listener, setup, 0..4, 1, Duration::from_millis(50), |_, monoliths| {
+            seen.extend(monoliths.into_iter().map(|mono| mono.seed));
+        });
+
+        seen.sort();
+        assert_eq!(seen, (0..4).collect::<Vec<_>>());
+        workers.into_iter().for_each(|worker| worker.join().unwrap());
+    }
+}
@@ -0,0 +1,31 @@
+use crate::*;
+
+#[derive(clap::Args)]
+pub struct EquivalentsCommand {
+    /// Seed to find friendlier equivalents of, signed or text
+    #[arg(short='v', long, default_value="617", allow_negative_numbers=true)]
+    value: UserSeed,
+
+    /// Longest text seeds to look for, each extra character is 62x slower
+    #[arg(short='l', long, default_value_t=6)]
+    length: usize,
+}
+
+impl EquivalentsCommand {
+    pub fn run(&self) {
+        let seed = self.value.seed();
+        let found = Equivalents::find(seed, self.length);
+
+        println!("Seed {seed} (48-bit) is the same world as:");
+
+        for long in &found.next_long {
+            println!(" • {long} (random world)");
+        }
+        if let Some(int) = found.int {
+            println!(" • {int} (int)");
+        }
+        for text in &found.text {
+            println!(" • \"{text}\" (text)");
+        }
+    }
+}
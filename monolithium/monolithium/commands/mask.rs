@@ -2,8 +2,8 @@ use crate::*;
 
 #[derive(clap::Args)]
 pub struct Mask {
-    #[arg(long, default_value_t=617)]
-    seed: Seed,
+    #[arg(long, default_value="617", allow_negative_numbers=true)]
+    seed: UserSeed,
 
     #[arg(long, default_value_t=-2000)]
     minx: i32,
@@ -19,22 +19,28 @@ pub struct Mask {
 impl Mask {
     pub fn run(&self) {
         let mut world  = World::new();
-        world.init(self.seed);
+        world.init(self.seed.seed());
 
         let width  = ((self.maxx - self.minx) as u32) / 4;
         let height = ((self.maxz - self.minz) as u32) / 4;
         let mut pixels = vec![0u8; (width * height) as usize];
 
-        let mut index = 0;
-        for x in (self.minx..self.maxx).step_by(4) {
-            for z in (self.minz..self.maxz).step_by(4) {
-                if world.is_monolith(x, z) {
+        let xs: Vec<i32> = (self.minx..self.maxx).step_by(4).collect();
+        let zs: Vec<i32> = (self.minz..self.maxz).step_by(4).collect();
+        let mut row = vec![false; xs.len()];
+
+        // Note: Sampled by rows of x, stored by columns
+        for (j, &z) in zs.iter().enumerate() {
+            world.monolith_row(self.minx, z, 4, &mut row);
+
+            for (i, &x) in xs.iter().enumerate() {
+                let index = i*zs.len() + j;
+                if row[i] {
                     pixels[index] = 255;
                 }
                 if (x % 250 == 0) || (z % 250 == 0) {
                     pixels[index] = 64;
                 }
-                index += 1;
             }
         }
 
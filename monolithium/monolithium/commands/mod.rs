@@ -1,6 +1,11 @@
+pub mod cluster;
+pub mod equivalents;
 pub mod mask;
+pub mod nearest;
 pub mod perlin;
 pub mod search;
+pub use equivalents::*;
 pub use mask::*;
+pub use nearest::*;
 pub use perlin::*;
 pub use search::*;
@@ -0,0 +1,35 @@
+use crate::*;
+
+#[derive(clap::Args)]
+pub struct NearestCommand {
+    #[arg(short='v', long, default_value="617", allow_negative_numbers=true)]
+    seed: UserSeed,
+
+    /// Coordinate X to measure from
+    #[arg(short='x', long, default_value_t=0, allow_negative_numbers=true)]
+    x: i32,
+
+    /// Coordinate Z to measure from
+    #[arg(short='z', long, default_value_t=0, allow_negative_numbers=true)]
+    z: i32,
+
+    /// How far away to look for monoliths, in blocks
+    #[arg(short='r', long, default_value_t=20000)]
+    radius: i32,
+}
+
+impl NearestCommand {
+    pub fn run(&self) {
+        let mut world = World::new();
+        world.init(self.seed.seed());
+
+        match world.nearest_monolith(self.x, self.z, self.radius) {
+            Some((mut stone, distance)) => {
+                stone.user_seed = Some(self.seed.clone());
+                println!("{}", serde_json::to_string(&stone).unwrap());
+                println!("Nearest Monolith is {:.0} blocks away", distance);
+            },
+            None => println!("No Monolith within {} blocks", self.radius),
+        }
+    }
+}
@@ -2,8 +2,8 @@ use crate::*;
 
 #[derive(clap::Args)]
 pub struct PerlinPng {
-    #[arg(short='s', long, default_value_t=617)]
-    seed: Seed,
+    #[arg(short='s', long, default_value="617", allow_negative_numbers=true)]
+    seed: UserSeed,
 
     #[arg(long, default_value_t=-2000)]
     minx: i32,
@@ -22,28 +22,19 @@ pub struct PerlinPng {
 impl PerlinPng {
     pub fn run(&self) {
         let mut world = World::new();
-        world.init(self.seed);
-
-        let mut pixels = vec![0u8; (self.size * self.size) as usize];
-
-        for x in 0..self.size {
-            for z in 0..self.size {
-                let index = (x + z * self.size) as usize;
-                let world_x = utils::lerp(
-                    (x as f64) / (self.size as f64),
-                    self.minx as f64,
-                    self.maxx as f64,
-                );
-                let world_z = utils::lerp(
-                    (z as f64) / (self.size as f64),
-                    self.minz as f64,
-                    self.maxz as f64,
-                );
-                let value = world.hill.sample(world_x, world_z).abs();
-                let pixel = ((value / world.hill.maxval()) * 255.0) as u8;
-                pixels[index] = pixel;
-            }
-        }
+        world.init(self.seed.seed());
+
+        let size = self.size as usize;
+        let mut values = vec![0.0; size * size];
+
+        world.hill.sample_grid(&Rect {
+            minx: self.minx as f64, maxx: self.maxx as f64,
+            minz: self.minz as f64, maxz: self.maxz as f64,
+        }, size, size, &mut values);
+
+        let pixels: Vec<u8> = values.iter()
+            .map(|value| ((value.abs() / world.hill.maxval()) * 255.0) as u8)
+            .collect();
 
         png::Encoder::new(std::fs::File::create("perlin.png").unwrap(), self.size, self.size)
             .write_header().unwrap()
@@ -1,4 +1,146 @@
 use crate::*;
+use crate::commands::cluster;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::Seek;
+use std::io::Write;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How many worlds to initialize at once in SIMD lanes
+const LANES: usize = 8;
+
+/// Work blocks per thread between chances to write a checkpoint
+const BATCH: u64 = 256;
+
+/// Progress of a search, to resume it exactly where it stopped
+#[derive(Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Seeds of each work block
+    pub chunks: u64,
+
+    /// Seeds to search in total
+    pub total: u64,
+
+    /// Which seeds these are, see `SeedFactory::identity()`
+    #[serde(default)]
+    pub seeds: String,
+
+    /// Part of the seeds searched
+    #[serde(default)]
+    pub shard: Shard,
+
+    /// Tradeoffs the seeds are searched with
+    #[serde(default)]
+    pub strategy: Strategy,
+
+    /// Cutoffs of the seeds skipped with `--filter-fracts`
+    #[serde(default)]
+    pub fracts: FractFilter,
+
+    /// Where and how monoliths are searched in each seed
+    #[serde(default)]
+    pub options: FindOptions,
+
+    /// Work blocks done, all of the ones before it
+    pub cursor: u64,
+
+    /// Seed of the shared random generator, if any
+    pub seed: Option<Seed>,
+
+    /// Bytes of the output file written by the work blocks done
+    #[serde(default)]
+    pub written: u64,
+
+    /// Monoliths kept so far, all of them or the largest with `--top`
+    pub monoliths: Vec<Monolith>,
+}
+
+impl Checkpoint {
+    pub fn load(path: &Path) -> Self {
+        let content = std::fs::read_to_string(path)
+            .expect("Could not read checkpoint file");
+        serde_json::from_str(&content)
+            .expect("Could not parse Checkpoint from JSON")
+    }
+
+    pub fn save(&self, path: &Path) {
+        replace(path, serde_json::to_string(self).unwrap());
+    }
+}
+
+/// Replace a file at once, so a crash never leaves half of one
+fn replace(path: &Path, content: String) {
+    let temp = path.with_extension("tmp");
+    std::fs::write(&temp, content).expect("Could not write file");
+    std::fs::rename(&temp, path).expect("Could not replace file");
+}
+
+/// Monoliths kept for the summary, all of them or only the largest ones
+struct Kept {
+    top: Option<usize>,
+    heap: BinaryHeap<Reverse<Monolith>>,
+    all: Vec<Monolith>,
+
+    /// File rewritten with the largest ones when they change
+    leaderboard: PathBuf,
+}
+
+impl Kept {
+    fn new(top: Option<usize>, leaderboard: PathBuf, monoliths: Vec<Monolith>) -> Self {
+        let mut this = Self {top, heap: BinaryHeap::new(), all: Vec::new(), leaderboard};
+        monoliths.into_iter().for_each(|mono| this.push(mono));
+        this
+    }
+
+    fn extend(&mut self, found: Vec<Monolith>) {
+        if self.top.is_none() {
+            return found.into_iter().for_each(|mono| self.push(mono));
+        }
+
+        let before = self.sorted();
+        found.into_iter().for_each(|mono| self.push(mono));
+        let after = self.sorted();
+
+        if before != after || !self.leaderboard.exists() {
+            replace(&self.leaderboard, after.iter().rev()
+                .map(|stone| serde_json::to_string(&stone).unwrap() + "\n")
+                .collect());
+        }
+    }
+
+    fn push(&mut self, mono: Monolith) {
+        let Some(top) = self.top else {
+            return self.all.push(mono);
+        };
+
+        // Smaller than all of a full heap or found again
+        if self.heap.len() >= top && self.heap.peek().is_some_and(|Reverse(min)| mono.area <= min.area) {
+            return;
+        }
+        if self.heap.iter().any(|Reverse(other)| *other == mono) {
+            return;
+        }
+
+        self.heap.push(Reverse(mono));
+        if self.heap.len() > top {
+            self.heap.pop();
+        }
+    }
+
+    /// Each monolith once, the largest last
+    fn sorted(&self) -> Vec<Monolith> {
+        let mut monoliths: Vec<Monolith> = match self.top {
+            Some(_) => self.heap.iter().map(|Reverse(mono)| mono.clone()).collect(),
+            None => self.all.iter().cloned().collect::<AHashSet<Monolith>>().into_iter().collect(),
+        };
+        monoliths.sort();
+        monoliths
+    }
+}
+
 
 #[derive(clap::Args)]
 pub struct SearchCommand {
@@ -6,8 +148,9 @@ pub struct SearchCommand {
     #[command(subcommand)]
     seeds: SeedFactory,
 
-    /// (Worker ) How many seeds each work block should process
-    #[arg(short='c', long, default_value_t=1)]
+    /// (Worker ) How many seeds each work block should process, initialized 8 at a time in SIMD lanes,
+    /// fewer when there wouldn't be a block for each thread
+    #[arg(short='c', long, default_value_t=8*LANES as u64)]
     chunks: u64,
 
     /// (Worker ) Use multithreading to search within a seed
@@ -26,10 +169,30 @@ pub struct SearchCommand {
     #[arg(short='r', long, default_value_t=100)]
     radius: i32,
 
+    /// (Where  ) Use a true euclidean radius around the center, not a square
+    #[arg(long, default_value_t=false)]
+    circle: bool,
+
+    /// (Where  ) Also search a shape, as rect:minx,maxx,minz,maxz circle:x,z,r annulus:x,z,inner,outer or polygon:x,z,x,z,..
+    #[arg(long, allow_hyphen_values=true)]
+    include: Vec<Shape>,
+
+    /// (Where  ) Skip blocks within a shape, same syntax as --include
+    #[arg(long, allow_hyphen_values=true)]
+    exclude: Vec<Shape>,
+
+    /// (Where  ) Json file with "include" and "exclude" lists of shapes
+    #[arg(long)]
+    region: Option<std::path::PathBuf>,
+
     /// (Where  ) Spacing between each check, in blocks
     #[arg(short='s', long, default_value_t=200)]
     step: usize,
 
+    /// (Where  ) Probe outwards from the center, so limits keep the nearest
+    #[arg(short='n', long, default_value_t=false)]
+    nearest: bool,
+
     /// (Limits ) Maximum number of monoliths to find in a seed
     #[arg(short='l', long, default_value_t=999999)]
     limit: u64,
@@ -38,6 +201,66 @@ pub struct SearchCommand {
     #[arg(short='a', long, default_value_t=0)]
     area: u64,
 
+    /// (Flood  ) Join cells by edges only (4) or also by corners (8)
+    #[arg(long, default_value_t=4, value_parser=clap::builder::TypedValueParser::map(
+        clap::builder::PossibleValuesParser::new(["4", "8"]), |v| v.parse::<u8>().unwrap()))]
+    connectivity: u8,
+
+    /// (Flood  ) Blocks away that pieces still merge into a monolith, 0 for single blobs
+    #[arg(long, default_value_t=128)]
+    satellites: i32,
+
+    /// (Flood  ) Blocks between cells updating coordinates and looking for satellites
+    #[arg(long, default_value_t=32)]
+    stride: i32,
+
+    /// (Flood  ) Blocks between cells sampled for the areas, a power of two
+    #[arg(long, default_value_t=4, value_parser=|v: &str| match v.parse::<i32>() {
+        Ok(n) if n > 0 && (n as u32).is_power_of_two() => Ok(n),
+        _ => Err(String::from("must be a power of two")),
+    })]
+    area_step: i32,
+
+    /// (Output ) Write monoliths to this file as they're found, not stdout
+    #[arg(short='o', long)]
+    output: Option<PathBuf>,
+
+    /// (Output ) Only keep the K largest monoliths for the summary
+    #[arg(long)]
+    top: Option<usize>,
+
+    /// (Output ) Rewrite the kept largest monoliths to this file as they change
+    #[arg(long, default_value="leaderboard.jsonl")]
+    leaderboard: PathBuf,
+
+    /// (Shard  ) Only search this part of the seeds, as index/count
+    #[arg(long, default_value="0/1")]
+    shard: Shard,
+
+    /// (Shard  ) Hand out the search to workers connecting at this address
+    #[arg(long)]
+    serve: Option<String>,
+
+    /// (Shard  ) Work on the search of a coordinator at this address
+    #[arg(long)]
+    worker: Option<String>,
+
+    /// (Shard  ) Seconds before work a worker hasn't finished is also handed to idle ones
+    #[arg(long, default_value_t=600)]
+    timeout: u64,
+
+    /// (Resume ) Periodically save progress and results to this file
+    #[arg(long)]
+    checkpoint: Option<PathBuf>,
+
+    /// (Resume ) Seconds between checkpoints
+    #[arg(long, default_value_t=60)]
+    every: u64,
+
+    /// (Resume ) Continue a search from a checkpoint, saving to it again
+    #[arg(long)]
+    resume: Option<PathBuf>,
+
     /// (Special) Set radius to the value hill noise wraps (262144)
     #[arg(short='h', long, default_value_t=false)]
     hill: bool,
@@ -45,75 +268,290 @@ pub struct SearchCommand {
     /// (Special) Set radius to the value depth noise wraps (4194304)
     #[arg(short='d', long, default_value_t=false)]
     depth: bool,
+
+    #[command(flatten)]
+    strategy: Strategy,
+
+    #[command(flatten)]
+    fracts: FractFilter,
 }
 
 impl SearchCommand {
     pub fn run(&mut self) {
+        if let Some(address) = self.worker.clone() {
+            return self.work(&address);
+        }
+
+        let mut state = match &self.resume {
+            Some(path) => Checkpoint::load(path),
+            None => Checkpoint {chunks: self.chunks, ..Default::default()},
+        };
+
+        // Draw the same random seeds again
+        if let Some(seed) = state.seed {
+            self.seeds.reseed(seed);
+        }
         self.seeds.initialize();
+        state.seed = self.seeds.rng_seed();
+
+        // Few seeds are still split across all threads
+        let seeds = self.seeds.shard(self.shard);
+        let threads = rayon::current_num_threads() as u64;
+        self.chunks = self.chunks.min((seeds.end - seeds.start).div_ceil(threads)).max(1);
 
-        // Standard math to split a work into many blocks
-        let chunks = (self.seeds.total() + self.chunks - 1) / self.chunks;
+        let options = self.options();
+
+        if state.cursor > 0 {
+            assert_eq!(state.chunks, self.chunks, "Resume with the same --chunks as the checkpoint");
+            assert_eq!(state.total, self.seeds.total(), "Resume with the same seeds as the checkpoint");
+            assert_eq!(state.seeds, self.seeds.identity(), "Resume with the same seeds as the checkpoint");
+            assert_eq!(state.options, options, "Resume with the same search flags as the checkpoint");
+            assert_eq!(state.shard, self.shard, "Resume with the same --shard as the checkpoint");
+            assert_eq!(state.strategy, self.strategy, "Resume with the same strategy flags as the checkpoint");
+            assert_eq!(state.fracts, self.fracts, "Resume with the same fracts flags as the checkpoint");
+        }
+        state.chunks = self.chunks;
+        state.total = self.seeds.total();
+        state.seeds = self.seeds.identity();
+        state.options = options.clone();
+        state.shard = self.shard;
+        state.strategy = self.strategy;
+        state.fracts = self.fracts;
+
+        let chunks = self.blocks();
 
         let progress = ProgressBar::new(chunks)
-            .with_style(utils::progress("Searching"));
+            .with_style(utils::progress("Searching"))
+            .with_position(state.cursor);
+
+        // Continue the output file after the work blocks done
+        let mut output: Box<dyn Write + Send> = match &self.output {
+            None => Box::new(std::io::stdout()),
+            Some(path) => {
+                let mut file = std::fs::OpenOptions::new()
+                    .write(true).create(true).truncate(false)
+                    .open(path).expect("Could not open output file");
+                file.set_len(state.written).unwrap();
+                file.seek(std::io::SeekFrom::End(0)).unwrap();
+                Box::new(std::io::BufWriter::new(file))
+            }
+        };
+
+        let users = self.seeds.user_seeds();
+        let mut kept = Kept::new(self.top, self.leaderboard.clone(), std::mem::take(&mut state.monoliths));
+
+        // Note: Coordinators can't checkpoint, as workers finish in any order
+        if let Some(address) = &self.serve {
+            let setup = cluster::Setup {
+                seed: self.seeds.rng_seed(),
+                total: self.seeds.total(),
+                seeds: self.seeds.identity(),
+                chunks: self.chunks,
+                shard: self.shard,
+                strategy: self.strategy,
+                fracts: self.fracts,
+                options: options.clone(),
+            };
+            let timeout = Duration::from_secs(self.timeout);
+            let listener = std::net::TcpListener::bind(address)
+                .expect("Could not listen on address");
+
+            cluster::coordinate(listener, setup, state.cursor..chunks, BATCH, timeout, |blocks, mut found| {
+                progress.inc(blocks.end - blocks.start);
+                Self::report(&mut found, &users, &mut output, &progress);
+                output.flush().unwrap();
+                kept.extend(found);
+            });
+        }
+
+        let checkpoint = self.checkpoint.as_ref().or(self.resume.as_ref());
+        let batch = BATCH * rayon::current_num_threads() as u64;
+        let mut saved = std::time::Instant::now();
+
+        // Work in batches, all of one done before saving progress
+        while self.serve.is_none() && state.cursor < chunks {
+            let end = (state.cursor + batch).min(chunks);
+            let stream = Mutex::new(&mut output);
+
+            let found = self.batch(state.cursor..end, &options, &progress, &|found| {
+                Self::report(found, &users, &mut *stream.lock().unwrap(), &progress);
+            });
+
+            output.flush().unwrap();
+            state.cursor = end;
+            kept.extend(found);
+
+            if let Some(path) = checkpoint
+                && (state.cursor == chunks || saved.elapsed().as_secs() >= self.every) {
+                state.written = self.output.as_ref().map_or(0, |path| std::fs::metadata(path).unwrap().len());
+                state.monoliths = kept.sorted();
+                state.save(path);
+                saved = std::time::Instant::now();
+            }
+        }
+        progress.finish();
+
+        // Summary goes apart from monoliths streamed to stdout
+        let monoliths = kept.sorted();
+        let summary = monoliths.iter()
+            .map(|stone| serde_json::to_string(&stone).unwrap())
+            .chain([format!("Found {} Monoliths", monoliths.len())]);
+
+        match self.output {
+            Some(_) => summary.for_each(|line| println!("{line}")),
+            None    => summary.for_each(|line| eprintln!("{line}")),
+        }
+    }
+
+    /// Search the work blocks a coordinator hands out, as it searches them
+    fn work(&mut self, address: &str) {
+        let (worker, setup) = cluster::Worker::connect(address);
+
+        if let Some(seed) = setup.seed {
+            self.seeds.reseed(seed);
+        }
+        self.seeds.initialize();
+        assert_eq!(setup.seeds, self.seeds.identity(), "Work on the same seeds as the coordinator");
+        self.chunks = setup.chunks;
+        self.shard = setup.shard;
+        self.strategy = setup.strategy;
+        self.fracts = setup.fracts;
+
+        // Search where the coordinator does, whatever flags this worker has
+        let options = setup.options;
+        let progress = ProgressBar::new(self.blocks())
+            .with_style(utils::progress("Working"));
+
+        worker.work(|blocks| self.batch(blocks, &options, &progress, &|_| {}));
+        progress.finish();
+    }
 
+    fn options(&self) -> FindOptions {
         let mut options = FindOptions::default()
             .around(self.center_x, self.center_z, self.radius)
             .threaded(self.threaded)
+            .nearest(self.nearest)
+            .connectivity(self.connectivity)
+            .satellites(self.satellites)
+            .stride(self.stride)
+            .area_step(self.area_step)
             .limit(self.limit)
             .area(self.area)
             .step(self.step);
 
         // Apply sugar options
-        if self.hill  {options = options.hill_wraps(); }
+        if self.hill  {options = options.hill_wraps(self.strategy.only_hill);}
         if self.depth {options = options.depth_wraps();}
 
+        // Shapes from the file, flags and circle, within the rectangle otherwise
+        let mut region = self.region.as_deref().map(Region::load).unwrap_or_default();
+        region.include.extend(self.include.iter().cloned());
+        region.exclude.extend(self.exclude.iter().cloned());
+        if self.circle {
+            region.include.push(Shape::Circle {x: self.center_x, z: self.center_z, radius: self.radius});
+        }
+        if !region.is_empty() {
+            options = options.region(region);
+        }
+
         // Infer threading if too few inputs
         if self.seeds.total() < 4 {
             options = options.threaded(true);
         }
 
-        let mut monoliths: Vec<Monolith> =
-            (0..chunks)
-            .into_par_iter()
-            .progress_with(progress)
-            .map_init(|| World::new(), |world, chunk| {
-                let min = (chunk + 0) * self.chunks;
-                let max = (chunk + 1) * self.chunks;
-
-                if cfg!(feature="sister-perlin") {
-                    let seed = self.seeds.get(min);
-                    world.init(seed);
-                }
+        options
+    }
 
-                (min..max).map(|seed| {
-                    if cfg!(feature="sister-perlin") {
-                        world.sister_perlin();
-                        world.find_monoliths(&options)
-                    } else {
-                        let seed = self.seeds.get(seed);
+    /// Standard math to split the seeds of the shard into many blocks
+    fn blocks(&self) -> u64 {
+        let seeds = self.seeds.shard(self.shard);
+        (seeds.end - seeds.start).div_ceil(self.chunks)
+    }
 
-                        #[cfg(feature="filter-fracts")]
-                        if !World::good_perlin_fracts(seed) {
-                            return Vec::new();
-                        }
+    /// Monoliths of a range of work blocks on all threads, each block's
+    /// also passed to `each` as soon as they're found
+    fn batch(&self,
+        blocks: Range<u64>,
+        options: &FindOptions,
+        progress: &ProgressBar,
+        each: &(dyn Fn(&mut [Monolith]) + Sync),
+    ) -> Vec<Monolith> {
+        specialize!(self.strategy, S => self.batch_as::<S>(blocks, options, progress, each))
+    }
 
-                        world.init(seed);
-                        world.find_monoliths(&options)
-                    }
-                }).flatten()
-                  .collect::<Vec<Monolith>>()
+    /// Same as `batch()`, with the worlds sampled as `S`
+    fn batch_as<S: Sampling>(&self,
+        blocks: Range<u64>,
+        options: &FindOptions,
+        progress: &ProgressBar,
+        each: &(dyn Fn(&mut [Monolith]) + Sync),
+    ) -> Vec<Monolith> {
+        blocks
+            .into_par_iter()
+            .progress_with(progress.clone())
+            .map_init(|| std::array::from_fn(|_| World::with(self.strategy).expect("Specialized to the strategy")), |worlds: &mut [World<S>; LANES], chunk| {
+                let mut found = self.chunk(worlds, chunk, options);
+                each(&mut found);
+                found
             })
             .flatten()
-            .collect();
+            .collect()
+    }
 
-        monoliths.sort();
+    /// Report seeds as the user wrote them, writing monoliths out as found
+    fn report(found: &mut [Monolith], users: &AHashMap<Seed, UserSeed>, output: &mut impl Write, progress: &ProgressBar) {
+        progress.suspend(|| for stone in found.iter_mut() {
+            stone.user_seed = users.get(&stone.seed).cloned();
+            writeln!(output, "{}", serde_json::to_string(&stone).unwrap()).unwrap();
+        });
+    }
+
+    /// Monoliths in the seeds of a work block
+    fn chunk<S: Sampling>(&self, worlds: &mut [World<S>; LANES], chunk: u64, options: &FindOptions) -> Vec<Monolith> {
+        let seeds = self.seeds.shard(self.shard);
+        let min = seeds.start + (chunk + 0) * self.chunks;
+        let max = (seeds.start + (chunk + 1) * self.chunks).min(seeds.end);
+
+        if self.strategy.sister_perlin {
+            let world = &mut worlds[0];
+            world.init(self.seeds.get(min));
 
-        for stone in monoliths.iter() {
-            println!("{}", serde_json::to_string(&stone).unwrap());
-            // println!(" • {}\n", stone.kahomayo_url());
+            return (min..max).flat_map(|_| {
+                world.sister_perlin();
+                let mut found = world.find_monoliths(options);
+
+                // Recover the real seed past shuffle rejections, dropping
+                // the monoliths of states no seed leads to exactly
+                if !found.is_empty() {
+                    match world.seed_from_state() {
+                        Some(seed) => {
+                            world.seed = seed;
+                            found.iter_mut().for_each(|mono| mono.seed = seed);
+                        },
+                        None => found.clear(),
+                    }
+                }
+                found
+            }).collect::<Vec<Monolith>>();
         }
 
-        println!("Found {} Monoliths", monoliths.len());
+        let seeds: Vec<Seed> = (min..max)
+            .map(|n| self.seeds.get(n))
+            .filter(|&seed| !self.strategy.filter_fracts || World::good_perlin_fracts(seed, &self.strategy, &self.fracts))
+            .collect();
+
+        // Initialize full groups of worlds in lockstep
+        seeds.chunks(LANES).flat_map(|group| {
+            match <[Seed; LANES]>::try_from(group) {
+                Ok(group) => World::init_lanes(worlds, group),
+                Err(_) => for (world, &seed) in worlds.iter_mut().zip(group) {
+                    world.init(seed);
+                },
+            }
+
+            worlds[..group.len()].iter()
+                .flat_map(|world| world.find_monoliths(options))
+                .collect::<Vec<Monolith>>()
+        }).collect::<Vec<Monolith>>()
     }
 }
@@ -0,0 +1,208 @@
+use crate::*;
+use std::sync::RwLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering::Relaxed;
+
+/// Cells along each side of a chunk, one bit each in a row's word
+const CHUNK: i32 = 64;
+
+/// Cells that were already checked for being part of a monolith
+type Chunk = [u64; CHUNK as usize];
+
+/// A growable grid of bits over integer cells, allocated in square chunks as
+/// the flood fill reaches them. Chunks are kept around for reuse on `clear()`
+#[derive(Default)]
+pub struct FloodGrid {
+    index: AHashMap<(i32, i32), usize>,
+    chunks: Vec<Chunk>,
+    used: usize,
+
+    /// Last chunk accessed, as fills are mostly local
+    last: Option<((i32, i32), usize)>,
+}
+
+/// A grid of visited cells a flood fill can work on
+pub trait FloodCells {
+
+    /// Mark a cell as visited, returns whether it wasn't already
+    fn visit(&mut self, x: i32, z: i32) -> bool;
+
+    fn visited(&mut self, x: i32, z: i32) -> bool;
+
+    /// The cell a given one is stored as, the same for all of its repeats
+    #[inline(always)]
+    fn wrap(&self, x: i32, z: i32) -> (i32, i32) {
+        (x, z)
+    }
+}
+
+impl<G: FloodCells> FloodCells for &mut G {
+
+    #[inline(always)]
+    fn visit(&mut self, x: i32, z: i32) -> bool {
+        (**self).visit(x, z)
+    }
+
+    #[inline(always)]
+    fn visited(&mut self, x: i32, z: i32) -> bool {
+        (**self).visited(x, z)
+    }
+
+    #[inline(always)]
+    fn wrap(&self, x: i32, z: i32) -> (i32, i32) {
+        (**self).wrap(x, z)
+    }
+}
+
+/// Cells of a grid repeating itself every `period` of them on both axes, as
+/// the world does, so a fill never visits the same cell twice across repeats
+pub struct Torus<G> {
+    pub cells: G,
+    pub period: i32,
+}
+
+impl<G: FloodCells> FloodCells for Torus<G> {
+
+    #[inline(always)]
+    fn visit(&mut self, x: i32, z: i32) -> bool {
+        let (x, z) = self.wrap(x, z);
+        self.cells.visit(x, z)
+    }
+
+    #[inline(always)]
+    fn visited(&mut self, x: i32, z: i32) -> bool {
+        let (x, z) = self.wrap(x, z);
+        self.cells.visited(x, z)
+    }
+
+    #[inline(always)]
+    fn wrap(&self, x: i32, z: i32) -> (i32, i32) {
+        (x.rem_euclid(self.period), z.rem_euclid(self.period))
+    }
+}
+
+/// Chunk, row and bit of a cell
+#[inline(always)]
+fn locate(x: i32, z: i32) -> ((i32, i32), usize, u64) {
+    (
+        (x.div_euclid(CHUNK), z.div_euclid(CHUNK)),
+        z.rem_euclid(CHUNK) as usize,
+        1u64 << x.rem_euclid(CHUNK),
+    )
+}
+
+thread_local! {
+    /// Reusable grid for flood fills on each (rayon) worker thread
+    pub static FLOOD_GRID: RefCell<FloodGrid> = RefCell::new(FloodGrid::default());
+}
+
+impl FloodGrid {
+
+    /// Forget all cells, keeping allocations
+    pub fn clear(&mut self) {
+        for chunk in &mut self.chunks[..self.used] {
+            *chunk = [0; CHUNK as usize];
+        }
+        self.index.clear();
+        self.used = 0;
+        self.last = None;
+    }
+
+    #[inline(always)]
+    fn chunk(&mut self, x: i32, z: i32) -> (&mut Chunk, usize, u64) {
+        let (key, row, bit) = locate(x, z);
+
+        let index = match self.last {
+            Some((last, index)) if last == key => index,
+            _ => {
+                let index = *self.index.entry(key).or_insert_with(|| {
+                    if self.used == self.chunks.len() {
+                        self.chunks.push([0; CHUNK as usize]);
+                    }
+                    self.used += 1;
+                    self.used - 1
+                });
+                self.last = Some((key, index));
+                index
+            }
+        };
+
+        (&mut self.chunks[index], row, bit)
+    }
+}
+
+impl FloodCells for FloodGrid {
+
+    #[inline(always)]
+    fn visit(&mut self, x: i32, z: i32) -> bool {
+        let (chunk, row, bit) = self.chunk(x, z);
+        let new = (chunk[row] & bit) == 0;
+        chunk[row] |= bit;
+        new
+    }
+
+    #[inline(always)]
+    fn visited(&mut self, x: i32, z: i32) -> bool {
+        let (chunk, row, bit) = self.chunk(x, z);
+        (chunk[row] & bit) != 0
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+type AtomicChunk = [AtomicU64; CHUNK as usize];
+
+/// Same as a FloodGrid, shared by many threads filling the same monolith. Each
+/// cell is claimed by exactly one of them, whoever visits it first
+#[derive(Default)]
+pub struct SharedFloodGrid {
+    chunks: RwLock<AHashMap<(i32, i32), Arc<AtomicChunk>>>,
+}
+
+impl SharedFloodGrid {
+    pub fn cursor(&self) -> SharedFloodCursor<'_> {
+        SharedFloodCursor {grid: self, last: None}
+    }
+}
+
+/// A thread's handle on a SharedFloodGrid, keeping the last chunk accessed
+pub struct SharedFloodCursor<'a> {
+    grid: &'a SharedFloodGrid,
+    last: Option<((i32, i32), Arc<AtomicChunk>)>,
+}
+
+impl SharedFloodCursor<'_> {
+
+    #[inline(always)]
+    fn chunk(&mut self, x: i32, z: i32) -> (&AtomicChunk, usize, u64) {
+        let (key, row, bit) = locate(x, z);
+
+        if !matches!(&self.last, Some((last, _)) if *last == key) {
+            let found = self.grid.chunks.read().unwrap().get(&key).cloned();
+            let chunk = found.unwrap_or_else(|| {
+                self.grid.chunks.write().unwrap()
+                    .entry(key)
+                    .or_insert_with(|| Arc::new(std::array::from_fn(|_| AtomicU64::new(0))))
+                    .clone()
+            });
+            self.last = Some((key, chunk));
+        }
+
+        (&self.last.as_ref().unwrap().1, row, bit)
+    }
+}
+
+impl FloodCells for SharedFloodCursor<'_> {
+
+    #[inline(always)]
+    fn visit(&mut self, x: i32, z: i32) -> bool {
+        let (chunk, row, bit) = self.chunk(x, z);
+        (chunk[row].fetch_or(bit, Relaxed) & bit) == 0
+    }
+
+    #[inline(always)]
+    fn visited(&mut self, x: i32, z: i32) -> bool {
+        let (chunk, row, bit) = self.chunk(x, z);
+        (chunk[row].load(Relaxed) & bit) != 0
+    }
+}
@@ -1,3 +1,7 @@
+// Explicit returns and `+ 0` offsets are on purpose
+#![allow(clippy::needless_return, clippy::identity_op)]
+
+pub use std::cell::RefCell;
 pub use std::cmp::Ordering;
 pub use std::collections::VecDeque;
 pub use std::hash::Hash;
@@ -7,6 +11,7 @@ pub use std::sync::LazyLock;
 pub use std::sync::Mutex;
 pub use std::sync::OnceLock;
 
+pub use ahash::AHashMap;
 pub use ahash::AHashSet;
 pub use clap::Parser;
 pub use clap::Subcommand;
@@ -20,16 +25,28 @@ pub use serde::Serialize;
 pub use smart_default::SmartDefault;
 
 pub mod commands;
+pub mod flood;
 pub mod monolith;
 pub mod perlin;
+pub mod probes;
+pub mod region;
 pub mod rng;
 pub mod seeds;
+pub mod strategy;
 pub mod utils;
 pub mod world;
+pub use flood::*;
 pub use monolith::*;
 pub use perlin::*;
+pub use probes::*;
+pub use region::*;
 pub use rng::JavaRNG;
+pub use rng::JavaRandom;
+pub use rng::JavaRNGx;
+pub use rng::JavaRNGx4;
+pub use rng::JavaRNGx8;
 pub use seeds::*;
+pub use strategy::*;
 pub use utils::*;
 pub use world::*;
 
@@ -7,11 +7,15 @@ use monolithium::commands::*;
 #[command(about="Finding the Largest Minecraft Alpha Monoliths")]
 enum Commands {
     /// Search for worlds with monoliths near spawn
-    Search(SearchCommand),
+    Search(Box<SearchCommand>),
+    /// Find the nearest monolith to a coordinate
+    Nearest(NearestCommand),
     /// Make an image of a world's monoliths
     Mask(Mask),
     /// Make an image of a world's perlin noise
     Perlin(PerlinPng),
+    /// List friendlier 64-bit seeds with the same world as a 48-bit one
+    Equivalents(EquivalentsCommand),
 }
 
 impl Commands {
@@ -19,7 +23,9 @@ impl Commands {
         match self {
             Commands::Mask(cmd)   => cmd.run(),
             Commands::Search(cmd) => cmd.run(),
+            Commands::Nearest(cmd) => cmd.run(),
             Commands::Perlin(cmd) => cmd.run(),
+            Commands::Equivalents(cmd) => cmd.run(),
         }
     }
 }
@@ -3,11 +3,38 @@ use crate::*;
 #[derive(Clone, Debug, Eq, Serialize, Deserialize)]
 pub struct Monolith {
     pub area: u64,
+
+    /// The 48-bit seed, also read from negative or text ones
+    #[serde(deserialize_with="UserSeed::deserialize_seed")]
     pub seed: Seed,
 
+    /// The seed as the user wrote it, when not the same number
+    #[serde(default, skip_serializing_if="Option::is_none")]
+    pub user_seed: Option<UserSeed>,
+
     // Position in the world
     pub minx: i32, pub maxx: i32,
     pub minz: i32, pub maxz: i32,
+
+    /// Smallest member block by (x, z), the same wherever it was found from
+    #[serde(default)]
+    pub id: (i32, i32),
+
+    /// Area before the Far Lands, which overwrite the rest
+    #[serde(default)]
+    pub inbounds: u64,
+
+    /// Whether the Far Lands overwrite some of it
+    #[serde(default)]
+    pub clipped: bool,
+
+    /// Blocks from its edges to the Far Lands, negative when past them
+    #[serde(default)]
+    pub farlands: i32,
+
+    /// Connected pieces merged into it as satellites
+    #[serde(default)]
+    pub components: u32,
 }
 
 /* -------------------------------------------------------------------------- */
@@ -21,6 +48,74 @@ impl Monolith {
         (self.minz + self.maxz) / 2
     }
 
+    /// Move by a whole number of repeats, every `period` blocks, so that
+    /// its identifying block lies within `[0, period)` on both axes.
+    /// Returns how far it was moved
+    pub fn wrap(&mut self, period: i32) -> (i32, i32) {
+        let dx = self.id.0.rem_euclid(period) - self.id.0;
+        let dz = self.id.1.rem_euclid(period) - self.id.1;
+        self.shift(dx, dz);
+        (dx, dz)
+    }
+
+    /// All repeats every `period` blocks whose coordinates overlap the
+    /// blocks before the Far Lands
+    pub fn copies(&self, period: i32) -> Vec<Monolith> {
+        let (far, period) = (FARLANDS as i64, period as i64);
+
+        // Repeats whose range overlaps the inbounds one
+        let repeats = |min: i32, max: i32| {
+            let (min, max) = (min as i64, max as i64);
+            (-far - max).div_euclid(period)..=(far - min).div_euclid(period)
+        };
+
+        let mut copies = Vec::new();
+        for kx in repeats(self.minx, self.maxx) {
+            for kz in repeats(self.minz, self.maxz) {
+                let (dx, dz) = (kx * period, kz * period);
+                if (self.maxx as i64 + dx) < -far || (self.maxz as i64 + dz) < -far {
+                    continue;
+                }
+                let mut copy = self.clone();
+                copy.shift(dx as i32, dz as i32);
+                copies.push(copy);
+            }
+        }
+        copies
+    }
+
+    /// Move by some blocks, as one of its copies
+    pub fn shift(&mut self, dx: i32, dz: i32) {
+        self.minx += dx; self.maxx += dx;
+        self.minz += dz; self.maxz += dz;
+        self.id = (self.id.0 + dx, self.id.1 + dz);
+    }
+
+    /// Blocks from the coordinates to the nearest Far Lands edge, negative
+    /// when past it, without overflowing anywhere in the world
+    pub fn farlands_distance(&self) -> i32 {
+        let far = FARLANDS as i64;
+        [
+            far - self.maxx as i64, self.minx as i64 + far,
+            far - self.maxz as i64, self.minz as i64 + far,
+        ].into_iter().min().unwrap()
+            .clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    /// Note what the Far Lands overwrite from the area before them, then
+    /// clip the coordinates at them, unless all of it is past them
+    pub fn clip_farlands(&mut self) {
+        self.clipped  = self.inbounds < self.area;
+        self.farlands = self.farlands_distance();
+        if self.inbounds == 0 {
+            return;
+        }
+        self.minx = self.minx.clamp(-FARLANDS, FARLANDS);
+        self.maxx = self.maxx.clamp(-FARLANDS, FARLANDS);
+        self.minz = self.minz.clamp(-FARLANDS, FARLANDS);
+        self.maxz = self.maxz.clamp(-FARLANDS, FARLANDS);
+    }
+
     /// Get a permalink for https://kahomayo.github.io/monolith-renderer
     pub fn kahomayo_url(&self) -> String {
         format!(
@@ -31,18 +126,18 @@ impl Monolith {
 }
 
 /* -------------------------------------------------------------------------- */
-// Monoliths are equal if they have the same coordinates
+// Monoliths are equal if they are the same one in the same world
 
 impl Hash for Monolith {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.minx.hash(state);
-        self.minz.hash(state);
+        self.seed.hash(state);
+        self.id.hash(state);
     }
 }
 
 impl PartialEq for Monolith {
     fn eq(&self, other: &Self) -> bool {
-        (self.minx == other.minx) && (self.minz == other.minz)
+        (self.seed == other.seed) && (self.id == other.id)
     }
 }
 
@@ -51,7 +146,7 @@ impl PartialEq for Monolith {
 
 impl PartialOrd for Monolith {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.area.cmp(&other.area))
+        Some(self.cmp(other))
     }
 }
 
@@ -11,6 +11,20 @@ static NEW_MAP: [u8; 256] = {
     array
 };
 
+/// Values at or above which `JavaRNG::next_i32_bound(256 - n)` rejects a draw
+static SHUFFLE_LIMITS: [u64; 256] = {
+    let mut array = [u64::MAX; 256];
+    let mut n = 0;
+    while n < 256 {
+        let max = (256 - n) as u64;
+        if !max.is_power_of_two() {
+            array[n] = (1u64 << 31) - ((1u64 << 31) % max);
+        }
+        n += 1;
+    }
+    array
+};
+
 /* -------------------------------------------------------------------------- */
 
 #[derive(Clone, Debug)]
@@ -22,6 +36,12 @@ pub struct Perlin {
     pub zoff: f64,
 }
 
+impl Default for Perlin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Perlin {
 
     /// Get an empty structure
@@ -36,30 +56,58 @@ impl Perlin {
 
     /// Get a filled structure from rng
     #[inline(always)]
-    pub fn from_rng(rng: &mut JavaRNG) -> Self {
+    pub fn from_rng(rng: &mut JavaRNG, rejection: Rejection) -> Self {
         let mut perlin = Perlin::new();
-        perlin.init(rng);
+        perlin.init(rng, rejection);
         perlin
     }
 
     #[inline(always)]
-    pub fn init(&mut self, rng: &mut JavaRNG) {
+    pub fn init(&mut self, rng: &mut JavaRNG, rejection: Rejection) {
+        match rejection {
+            Rejection::Skip => self.shuffle::<true>(rng),
+            Rejection::Checked if !Perlin::rejects(rng) => self.shuffle::<true>(rng),
+            _ => self.shuffle::<false>(rng),
+        }
+    }
+
+    #[inline(always)]
+    fn shuffle<const UNCHECKED: bool>(&mut self, rng: &mut JavaRNG) {
         self.xoff = rng.next_f64_256();
         self.yoff = rng.next_f64_256();
         self.zoff = rng.next_f64_256();
 
-        // Shuffle the array
+        // Shuffle a fresh array, structs are reused
+        self.map = NEW_MAP;
         seq!(a in 0..256 {
-            let b = rng.next_i32_bound(256 - a) as usize;
+            let b = match UNCHECKED {
+                true  => rng.next_i32_bound_unchecked(256 - a),
+                false => rng.next_i32_bound(256 - a),
+            } as usize;
             self.map.swap(a, a + b);
         });
     }
 
+    /// Whether any permutation draw of a PerlinNoise starting at this state would
+    /// be rejected. All draws are independent jumps from it, computed at once
+    #[inline(always)]
+    pub fn rejects(rng: &JavaRNG) -> bool {
+        let mut rejected = false;
+
+        for (n, &limit) in SHUFFLE_LIMITS.iter().enumerate() {
+            let (a_n, c_n) = rng::SKIP_TABLE_NEXT[3*2 + n + 1];
+            let take = (rng.state.wrapping_mul(a_n).wrapping_add(c_n) & rng::M) >> 17;
+            rejected |= take >= limit;
+        }
+
+        rejected
+    }
+
     /// Similar function to a smoothstep, specific for perlin
     /// - https://en.wikipedia.org/wiki/Smoothstep
     #[inline(always)]
-    pub fn fade(t: f64) -> f64 {
-        if cfg!(feature="linear-fade") {
+    pub fn fade<S: Sampling>(t: f64) -> f64 {
+        if S::LINEAR_FADE {
             return t;
         } else {
             t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
@@ -111,7 +159,7 @@ impl Perlin {
     /// Sample the noise at a given coordinate
     /// - Note: For monoliths, y is often 0.0
     #[inline(always)]
-    pub fn sample(&self, x: f64, y: f64, z: f64) -> f64 {
+    pub fn sample<S: Sampling>(&self, x: f64, y: f64, z: f64) -> f64 {
         use utils::lerp;
 
         // Apply offsets
@@ -130,9 +178,9 @@ impl Perlin {
         let zf: f64 = z - z.floor();
 
         // Smoothstep-like factors
-        let u: f64 = Self::fade(xf);
-        let v: f64 = Self::fade(yf);
-        let w: f64 = Self::fade(zf);
+        let u: f64 = Self::fade::<S>(xf);
+        let v: f64 = Self::fade::<S>(yf);
+        let w: f64 = Self::fade::<S>(zf);
 
         // Get the hash values for the corners
         let a  = self.get_map(xi + 0 + 0) as usize;
@@ -159,18 +207,213 @@ impl Perlin {
         );
     }
 
-    /// Roll the generator state that would have created a PerlinNoise
+    /// Add samples of the noise times a scale for points `step` apart along a
+    /// row at y=0, reusing the lattice cell's hashes while within the same one
+    #[inline(always)]
+    pub fn sample_row<S: Sampling>(&self, x0: f64, z: f64, step: f64, scale: f64, out: &mut [f64]) {
+        use utils::lerp;
+
+        // Constant along the row
+        let y: f64 = self.yoff;
+        let z: f64 = z + self.zoff;
+        let yi: usize = (y.floor() as usize) & 0xFF;
+        let zi: usize = (z.floor() as usize) & 0xFF;
+        let yf: f64 = y - y.floor();
+        let zf: f64 = z - z.floor();
+        let v: f64 = Self::fade::<S>(yf);
+        let w: f64 = Self::fade::<S>(zf);
+
+        // Gradients of the current cell's corners as linear functions of their
+        // x distance, the other terms being constant along the row
+        let mut cell = usize::MAX;
+        let mut slope = [0.0; 8];
+        let mut inter = [0.0; 8];
+
+        for (i, out) in out.iter_mut().enumerate() {
+            let x: f64 = (x0 + (i as f64) * step) + self.xoff;
+            let xi: usize = (x.floor() as usize) & 0xFF;
+            let xf: f64 = x - x.floor();
+            let xg: f64 = xf - 1.0;
+            let u: f64 = Self::fade::<S>(xf);
+
+            if xi != cell {
+                let a  = self.get_map(xi + 0 + 0) as usize;
+                let aa = self.get_map(yi + a + 0) as usize;
+                let ab = self.get_map(yi + a + 1) as usize;
+                let b  = self.get_map(xi + 1 + 0) as usize;
+                let ba = self.get_map(yi + b + 0) as usize;
+                let bb = self.get_map(yi + b + 1) as usize;
+
+                for (k, (hash, y, z)) in [
+                    (aa + zi,     yf,       zf      ),
+                    (ba + zi,     yf,       zf      ),
+                    (ab + zi,     yf - 1.0, zf      ),
+                    (bb + zi,     yf - 1.0, zf      ),
+                    (aa + zi + 1, yf,       zf - 1.0),
+                    (ba + zi + 1, yf,       zf - 1.0),
+                    (ab + zi + 1, yf - 1.0, zf - 1.0),
+                    (bb + zi + 1, yf - 1.0, zf - 1.0),
+                ].into_iter().enumerate() {
+                    (slope[k], inter[k]) = Self::grad_x(self.get_map(hash), y, z);
+                }
+                cell = xi;
+            }
+
+            // Note: Exactly the same values as in a sample
+            let value = lerp(w,
+                lerp(v,
+                    lerp(u, slope[0]*xf + inter[0], slope[1]*xg + inter[1]),
+                    lerp(u, slope[2]*xf + inter[2], slope[3]*xg + inter[3])
+                ),
+                lerp(v,
+                    lerp(u, slope[4]*xf + inter[4], slope[5]*xg + inter[5]),
+                    lerp(u, slope[6]*xf + inter[6], slope[7]*xg + inter[7])
+                ),
+            );
+
+            *out += value * scale;
+        }
+    }
+
+    /// Split a gradient into the sign of x and the sum of the other terms, as
+    /// `grad(hash, x, y, z) == slope*x + inter`, all of them having two terms
+    #[inline(always)]
+    fn grad_x(hash: u8, y: f64, z: f64) -> (f64, f64) {
+        match hash & 0x0F {
+            0  => ( 1.0,  y),
+            1  => (-1.0,  y),
+            2  => ( 1.0, -y),
+            3  => (-1.0, -y),
+            4  => ( 1.0,  z),
+            5  => (-1.0,  z),
+            6  => ( 1.0, -z),
+            7  => (-1.0, -z),
+            8  => ( 0.0,  y + z),
+            9  => ( 0.0, -y + z),
+            10 => ( 0.0,  y - z),
+            11 => ( 0.0, -y - z),
+            12 => ( 1.0,  y),
+            13 => ( 0.0, -y + z),
+            14 => (-1.0,  y),
+            15 => ( 0.0, -y - z),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Lower and upper bounds of the noise over a rectangle at y=0, from interval
+    /// arithmetic within each lattice cell it covers. Wider ones are unbounded
+    /// at [-1, 1], the same assumption as the early exits in monolith checks
+    pub fn bounds_over<S: Sampling>(&self, rect: &Rect) -> (f64, f64) {
+        let x0 = rect.minx + self.xoff;
+        let x1 = rect.maxx + self.xoff;
+        let z0 = rect.minz + self.zoff;
+        let z1 = rect.maxz + self.zoff;
+
+        // Note: Either one or two cells per axis
+        let cells_x = (x1.floor() - x0.floor()) as usize;
+        let cells_z = (z1.floor() - z0.floor()) as usize;
+        if cells_x > 1 || cells_z > 1 {
+            return (-1.0, 1.0);
+        }
+
+        let y  = self.yoff;
+        let yi = (y.floor() as usize) & 0xFF;
+        let yf = y - y.floor();
+        let v  = Self::fade::<S>(yf);
+        let mut bounds = (f64::INFINITY, f64::NEG_INFINITY);
+
+        for cx in 0..=cells_x {
+            let cx = x0.floor() + cx as f64;
+            let xi = (cx as usize) & 0xFF;
+            let xf = ((x0 - cx).max(0.0), (x1 - cx).min(1.0));
+            let xg = (xf.0 - 1.0, xf.1 - 1.0);
+            let u  = (Self::fade::<S>(xf.0), Self::fade::<S>(xf.1));
+
+            for cz in 0..=cells_z {
+                let cz = z0.floor() + cz as f64;
+                let zi = (cz as usize) & 0xFF;
+                let zf = ((z0 - cz).max(0.0), (z1 - cz).min(1.0));
+                let zg = (zf.0 - 1.0, zf.1 - 1.0);
+                let w  = (Self::fade::<S>(zf.0), Self::fade::<S>(zf.1));
+
+                // Same hashes as a sample within this cell
+                let a  = self.get_map(xi + 0 + 0) as usize;
+                let aa = self.get_map(yi + a + 0) as usize;
+                let ab = self.get_map(yi + a + 1) as usize;
+                let b  = self.get_map(xi + 1 + 0) as usize;
+                let ba = self.get_map(yi + b + 0) as usize;
+                let bb = self.get_map(yi + b + 1) as usize;
+                let grad = |hash: usize, x: (f64, f64), y: f64, z: (f64, f64)| {
+                    Self::grad_bounds(self.get_map(hash), x, y, z)
+                };
+
+                let lerp = Self::lerp_bounds;
+                let cell = lerp(w,
+                    lerp((v, v),
+                        lerp(u, grad(aa + zi, xf, yf,       zf),
+                                grad(ba + zi, xg, yf,       zf)),
+                        lerp(u, grad(ab + zi, xf, yf - 1.0, zf),
+                                grad(bb + zi, xg, yf - 1.0, zf))
+                    ),
+                    lerp((v, v),
+                        lerp(u, grad(aa + zi + 1, xf, yf,       zg),
+                                grad(ba + zi + 1, xg, yf,       zg)),
+                        lerp(u, grad(ab + zi + 1, xf, yf - 1.0, zg),
+                                grad(bb + zi + 1, xg, yf - 1.0, zg))
+                    ),
+                );
+
+                bounds.0 = bounds.0.min(cell.0);
+                bounds.1 = bounds.1.max(cell.1);
+            }
+        }
+
+        (bounds.0.max(-1.0), bounds.1.min(1.0))
+    }
+
+    /// Range of a gradient over intervals of x and z, linear on both
+    #[inline(always)]
+    fn grad_bounds(hash: u8, x: (f64, f64), y: f64, z: (f64, f64)) -> (f64, f64) {
+        let corners = [
+            Self::grad(hash, x.0, y, z.0),
+            Self::grad(hash, x.0, y, z.1),
+            Self::grad(hash, x.1, y, z.0),
+            Self::grad(hash, x.1, y, z.1),
+        ];
+        (
+            corners.into_iter().fold(f64::INFINITY, f64::min),
+            corners.into_iter().fold(f64::NEG_INFINITY, f64::max),
+        )
+    }
+
+    /// Range of a linear interpolation, increasing on both ends for t in [0, 1]
     #[inline(always)]
-    pub fn discard(rng: &mut JavaRNG, many: usize) {
+    fn lerp_bounds(t: (f64, f64), a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        use utils::lerp;
+        (
+            lerp(t.0, a.0, b.0).min(lerp(t.1, a.0, b.0)),
+            lerp(t.0, a.1, b.1).max(lerp(t.1, a.1, b.1)),
+        )
+    }
+
+    /// Roll the generator state that would have created a PerlinNoise.
+    /// Note: Never forcibly inlined, the unrolled shuffles are large
+    pub fn discard(rng: &mut JavaRNG, many: usize, rejection: Rejection) {
 
         // Super fast but slightly lossy
-        if cfg!(feature="skip-rejection") {
-            rng.step_n(many*(3*2 + 256));
+        if rejection == Rejection::Skip {
+            rng.step_n((many*(3*2 + 256)) as u64);
             return;
         }
 
         for _ in 0..many {
 
+            // Jump over it if no draws would be rejected
+            if rejection == Rejection::Checked && !Perlin::rejects(rng) {
+                rng.step_n(3*2 + 256);
+                continue;
+            }
+
             // Coordinates f64 offsets
             rng.step_n(3*2);
 
@@ -181,27 +424,178 @@ impl Perlin {
         }
     }
 
-    /// Roll back the generator state that would have created a PerlinNoise (lossy)
+    /// Roll the generators' states that would have created a PerlinNoise each
+    pub fn discard_lanes<const LANES: usize>(rng: &mut JavaRNGx<LANES>, many: usize, rejection: Rejection) {
+        if rejection == Rejection::Skip {
+            rng.step_n((many*(3*2 + 256)) as u64);
+            return;
+        }
+
+        for _ in 0..many {
+            if rejection == Rejection::Checked && !(0..LANES).any(|lane| Perlin::rejects(&rng.lane(lane))) {
+                rng.step_n(3*2 + 256);
+                continue;
+            }
+
+            rng.step_n(3*2);
+            seq!(N in 0..256 {
+                rng.next_i32_bound(256 - N);
+            });
+        }
+    }
+
+    /// Roll back the generator state that would have created a PerlinNoise,
+    /// exact unless a shuffle rejected a draw, see `undiscard_exact()`
     #[inline(always)]
     pub fn undiscard(rng: &mut JavaRNG, many: usize) {
-        rng.back_n(many*(3*2 + 256));
+        rng.back_n((many*(3*2 + 256)) as u64);
+    }
+
+    /// Same as `undiscard()`, also past rejections in the shuffles
+    ///
+    /// Rejections in the shuffles only ever add draws, so guess the state without
+    /// any, then walk by how far rolling it forward misses the current one. False
+    /// if no state rolls exactly onto ours (extremely rare), left at the last guess
+    ///
+    /// - Discards all octaves again at least once, only for seeds worth it
+    ///
+    pub fn undiscard_exact(rng: &mut JavaRNG, many: usize, rejection: Rejection) -> bool {
+        let target = rng.state;
+        Perlin::undiscard(rng, many);
+
+        for _ in 0..16 {
+            let mut probe = *rng;
+            Perlin::discard(&mut probe, many, rejection);
+
+            match JavaRNG::distance(target, probe.state) {
+                Some(0) => return true,
+                None => return false,
+                Some(ahead) if ahead <= rng::M/2 => rng.back_n(ahead),
+                Some(behind) => rng.step_n((rng::M + 1) - behind),
+            }
+        }
+        false
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
+/// A Perlin noise whose offsets and permutations are only generated on first
+/// use, from the generator state it would have been initialized with
 #[derive(Clone, Debug)]
-pub struct FractalPerlin<const OCTAVES: usize> {
+pub struct LazyPerlin {
+    rng: JavaRNG,
+    rejection: Rejection,
+    perlin: OnceLock<Perlin>,
+}
+
+impl Default for LazyPerlin {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LazyPerlin {
+    pub fn new() -> Self {
+        LazyPerlin {
+            rng: JavaRNG::from_state(0),
+            rejection: Rejection::Exact,
+            perlin: OnceLock::new(),
+        }
+    }
+
+    /// Get a deferred structure from rng
+    #[inline(always)]
+    pub fn from_rng(rng: &mut JavaRNG, rejection: Rejection) -> Self {
+        let mut perlin = LazyPerlin::new();
+        perlin.init(rng, rejection);
+        perlin
+    }
+
+    /// Remember the current state and roll the generator past this noise
+    #[inline(always)]
+    pub fn init(&mut self, rng: &mut JavaRNG, rejection: Rejection) {
+        self.rng = *rng;
+        self.rejection = rejection;
+        self.perlin = OnceLock::new();
+        Perlin::discard(rng, 1, rejection);
+    }
+
+    /// Same as `init()`, with each noise's state from a generator lane. Only the
+    /// discards run in the lanes, the shuffles wait for each lane's first sample
+    pub fn init_lanes<const LANES: usize>(
+        mut perlins: [&mut LazyPerlin; LANES],
+        rng: &mut JavaRNGx<LANES>,
+        rejection: Rejection,
+    ) {
+        for (lane, perlin) in perlins.iter_mut().enumerate() {
+            perlin.rng = rng.lane(lane);
+            perlin.rejection = rejection;
+            perlin.perlin = OnceLock::new();
+        }
+        Perlin::discard_lanes(rng, 1, rejection);
+    }
+
+    /// Whether the noise was already generated by sampling it
+    pub fn shuffled(&self) -> bool {
+        self.perlin.get().is_some()
+    }
+}
+
+impl std::ops::Deref for LazyPerlin {
+    type Target = Perlin;
+
+    #[inline(always)]
+    fn deref(&self) -> &Perlin {
+        self.perlin.get_or_init(|| Perlin::from_rng(&mut self.rng.clone(), self.rejection))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An axis aligned rectangle on the noise coordinates' xz plane
+#[derive(Clone, Copy, Debug)]
+pub struct Rect {
+    pub minx: f64,
+    pub maxx: f64,
+    pub minz: f64,
+    pub maxz: f64,
+}
+
+impl Rect {
+    #[inline(always)]
+    pub fn scale(&self, factor: f64) -> Rect {
+        Rect {
+            minx: self.minx * factor,
+            maxx: self.maxx * factor,
+            minz: self.minz * factor,
+            maxz: self.maxz * factor,
+        }
+    }
+}
+
+/// Most points sampled at once along a row
+pub const ROW_SAMPLES: usize = 64;
+
+/// Hill noise values must be below this to form a monolith
+pub const HILL_TARGET: f64 = -512.0;
+
+/// Depth noise absolute values must be above this to form a monolith
+pub const DEPTH_TARGET: f64 = 8000.0;
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Clone, Debug)]
+pub struct FractalPerlin<const OCTAVES: usize, S: Sampling = Compiled> {
 
     #[cfg(feature="deque-octaves")]
-    pub noise: VecDeque<Perlin>,
+    pub noise: VecDeque<LazyPerlin>,
 
     #[cfg(not(feature="deque-octaves"))]
-    pub noise: [Perlin; OCTAVES],
-}
+    pub noise: [LazyPerlin; OCTAVES],
 
-/// Ignored count of lower octaves, as they contribute very little
-pub const OCTAVES_START: usize = if cfg!(feature="most-octaves") {3} else {0};
+    sampling: std::marker::PhantomData<S>,
+}
 
 /// Lookup table for octave amplitudes
 pub static OCTAVE_SCALE_MUL: [f64; 32] = {
@@ -225,50 +619,112 @@ pub static OCTAVE_SCALE_DIV: [f64; 32] = {
     array
 };
 
-impl<const OCTAVES: usize> FractalPerlin<OCTAVES> {
+impl<const OCTAVES: usize, S: Sampling> Default for FractalPerlin<OCTAVES, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const OCTAVES: usize, S: Sampling> FractalPerlin<OCTAVES, S> {
 
     #[inline(always)]
     #[cfg(feature="deque-octaves")]
     pub fn new() -> Self {
         // Important: Start with a capacity, to win amortized complexity game
-        let mut noise: VecDeque<Perlin> = VecDeque::with_capacity(OCTAVES);
-        for _ in 0..OCTAVES {noise.push_back(Perlin::new());}
-        FractalPerlin {noise}
+        let mut noise: VecDeque<LazyPerlin> = VecDeque::with_capacity(OCTAVES);
+        for _ in 0..OCTAVES {noise.push_back(LazyPerlin::new());}
+        FractalPerlin {noise, sampling: std::marker::PhantomData}
     }
 
     #[inline(always)]
     #[cfg(not(feature="deque-octaves"))]
     pub fn new() -> Self {
         FractalPerlin {
-            noise: std::array::from_fn(|_| Perlin::new()),
+            noise: std::array::from_fn(|_| LazyPerlin::new()),
+            sampling: std::marker::PhantomData,
         }
     }
 
+    /// Remember each octave's generator state, shuffled only when first sampled
     #[inline(always)]
-    pub fn init(&mut self, rng: &mut JavaRNG) {
-        if cfg!(feature="skip-rejection") {
-            Perlin::discard(rng, OCTAVES_START);
+    pub fn init(&mut self, rng: &mut JavaRNG, rejection: Rejection) {
+        for i in 0..OCTAVES {
+            self.noise[i].init(rng, rejection);
+        }
+    }
 
-            for i in OCTAVES_START..OCTAVES {
-                self.noise[i].init(rng);
-            }
-        } else {
-            for i in 0..OCTAVES {
-                self.noise[i].init(rng);
-            }
+    /// Initialize many fractal noises at once, each from its own generator lane
+    #[inline(always)]
+    pub fn init_lanes<const LANES: usize>(
+        mut fracts: [&mut Self; LANES],
+        rng: &mut JavaRNGx<LANES>,
+        rejection: Rejection,
+    ) {
+        for i in 0..OCTAVES {
+            LazyPerlin::init_lanes(fracts.each_mut().map(|fract| &mut fract.noise[i]), rng, rejection);
         }
     }
 
     /// Sample the fractal noise at a given coordinate
     #[inline(always)]
     pub fn sample(&self, x: f64, z: f64) -> f64 {
-        (OCTAVES_START..OCTAVES).map(|i| {
+        (S::OCTAVES_START..OCTAVES).map(|i| {
             let mul = Self::octave_scale_mul_f64(i);
             let div = Self::octave_scale_div_f64(i);
-            self.noise[i].sample(x*div, 0.0, z*div) * mul
+            self.noise[i].sample::<S>(x*div, 0.0, z*div) * mul
         }).sum()
     }
 
+    /// Sample the fractal noise for `n` points `step` apart along a row, summed
+    /// from the most influential octaves, the same as the monolith checks
+    #[inline(always)]
+    pub fn sample_row(&self, x0: f64, z: f64, step: f64, n: usize, out: &mut [f64]) {
+        let out = &mut out[..n];
+        out.fill(0.0);
+
+        for i in (S::OCTAVES_START..OCTAVES).rev() {
+            let mul = Self::octave_scale_mul_f64(i);
+            let div = Self::octave_scale_div_f64(i);
+            self.noise[i].sample_row::<S>(x0*div, z*div, step*div, mul, out);
+        }
+    }
+
+    /// Sample the fractal noise on a grid of `nx` by `nz` points evenly spaced
+    /// from the rectangle's minimums, excluding its maximums, by rows of x
+    pub fn sample_grid(&self, rect: &Rect, nx: usize, nz: usize, out: &mut [f64]) {
+        let xstep = (rect.maxx - rect.minx) / (nx as f64);
+        let zstep = (rect.maxz - rect.minz) / (nz as f64);
+
+        for (j, row) in out[..nx*nz].chunks_mut(nx).enumerate() {
+            let z = rect.minz + (j as f64) * zstep;
+            self.sample_row(rect.minx, z, xstep, nx, row);
+        }
+    }
+
+    /// Lower and upper bounds of the fractal noise over a rectangle
+    /// - Octaves spanning over a lattice cell are only bounded by their scale
+    #[inline(always)]
+    pub fn bounds_over(&self, rect: &Rect) -> (f64, f64) {
+        (S::OCTAVES_START..OCTAVES)
+            .map(|octave| self.octave_bounds_over(octave, rect))
+            .fold((0.0, 0.0), |a, b| (a.0 + b.0, a.1 + b.1))
+    }
+
+    /// Scaled bounds of one octave over a rectangle
+    #[inline(always)]
+    fn octave_bounds_over(&self, octave: usize, rect: &Rect) -> (f64, f64) {
+        let mul = Self::octave_scale_mul_f64(octave);
+        let div = Self::octave_scale_div_f64(octave);
+
+        // Avoid initializing lazy octaves for nothing
+        if (rect.maxx - rect.minx)*div >= 1.0 || (rect.maxz - rect.minz)*div >= 1.0 {
+            return (-mul, mul);
+        }
+
+        let (lo, hi) = self.noise[octave].bounds_over::<S>(&rect.scale(div));
+        (lo * mul, hi * mul)
+    }
+
     /// Value at which the noise wraps around and repeats.
     /// - For Perlin noise, this value is 256 without any scaling
     /// - Each octave halves the frequency, extending it
@@ -309,20 +765,20 @@ impl<const OCTAVES: usize> FractalPerlin<OCTAVES> {
 
 /// Most coordinates are nowhere close to being monoliths, optimization to
 /// discard sums where reaching a target with next octave is impossible
-impl<const OCTAVES: usize> FractalPerlin<OCTAVES> {
+impl<const OCTAVES: usize, S: Sampling> FractalPerlin<OCTAVES, S> {
 
     #[inline(always)]
     pub fn is_hill_monolith(&self, x: i32, z: i32) -> bool {
-        const TARGET: f64 = -512.0;
+        const TARGET: f64 = HILL_TARGET;
         let x = (x >> 2) as f64;
         let z = (z >> 2) as f64;
         let mut sum = 0.0;
 
         // Start from most influential octaves
-        for octave in (OCTAVES_START..OCTAVES).rev() {
+        for octave in (S::OCTAVES_START..OCTAVES).rev() {
             let mul = Self::octave_scale_mul_f64(octave);
             let div = Self::octave_scale_div_f64(octave);
-            sum += self.noise[octave].sample(x*div, 0.0, z*div) * mul;
+            sum += self.noise[octave].sample::<S>(x*div, 0.0, z*div) * mul;
 
             // Next octaves cannot possibly reach target
             if sum - mul > TARGET {
@@ -340,16 +796,17 @@ impl<const OCTAVES: usize> FractalPerlin<OCTAVES> {
 
     #[inline(always)]
     pub fn is_depth_monolith(&self, x: i32, z: i32) -> bool {
-        const TARGET: f64 = 8000.0;
-        let x = (25 * x) as f64;
-        let z = (25 * z) as f64;
+        const TARGET: f64 = DEPTH_TARGET;
+        // Note: Overflows like Java's ints past the Far Lands
+        let x = x.wrapping_mul(25) as f64;
+        let z = z.wrapping_mul(25) as f64;
         let mut sum = 0.0;
 
         // Start from most influential octaves
-        for octave in (OCTAVES_START..OCTAVES).rev() {
+        for octave in (S::OCTAVES_START..OCTAVES).rev() {
             let mul = Self::octave_scale_mul_f64(octave);
             let div = Self::octave_scale_div_f64(octave);
-            sum += self.noise[octave].sample(x*div, 0.0, z*div) * mul;
+            sum += self.noise[octave].sample::<S>(x*div, 0.0, z*div) * mul;
 
             // Next octaves cannot possibly reach target
             if (sum.abs() + mul) < TARGET {
@@ -364,4 +821,136 @@ impl<const OCTAVES: usize> FractalPerlin<OCTAVES> {
 
         sum.abs() > TARGET
     }
+
+    /// Whether any point within a rectangle could be below the hill target
+    #[inline(always)]
+    pub fn may_hill_monolith(&self, rect: &Rect) -> bool {
+        const TARGET: f64 = HILL_TARGET;
+        self.may_monolith(rect,
+            |(lo, _), mul| {
+                if lo - mul >= TARGET {return Some(false);}
+                if lo + mul <  TARGET {return Some(true);}
+                None
+            },
+            |(lo, _)| lo < TARGET,
+        )
+    }
+
+    /// Whether any point within a rectangle could be beyond the depth target
+    #[inline(always)]
+    pub fn may_depth_monolith(&self, rect: &Rect) -> bool {
+        const TARGET: f64 = DEPTH_TARGET;
+        self.may_monolith(rect,
+            |(lo, hi), mul| {
+                if (lo - mul >= -TARGET) && (hi + mul <= TARGET) {return Some(false);}
+                if (lo + mul <  -TARGET) || (hi - mul >  TARGET) {return Some(true);}
+                None
+            },
+            |(lo, hi)| (lo < -TARGET) || (hi > TARGET),
+        )
+    }
+
+    /// Bound a rectangle with the same early exits after each octave
+    #[inline(always)]
+    fn may_monolith(&self, rect: &Rect,
+        exit: impl Fn((f64, f64), f64) -> Option<bool>,
+        last: impl Fn((f64, f64)) -> bool,
+    ) -> bool {
+        let mut bounds = (0.0, 0.0);
+
+        // Start from most influential octaves
+        for octave in (S::OCTAVES_START..OCTAVES).rev() {
+            let (lo, hi) = self.octave_bounds_over(octave, rect);
+            bounds.0 += lo;
+            bounds.1 += hi;
+
+            if let Some(may) = exit(bounds, Self::octave_scale_mul_f64(octave)) {
+                return may;
+            }
+        }
+
+        last(bounds)
+    }
+
+    /// Same as `is_hill_monolith()` for blocks `step` apart along a row
+    #[inline(always)]
+    pub fn is_hill_monolith_row(&self, x: i32, z: i32, step: i32, out: &mut [bool]) {
+        const TARGET: f64 = HILL_TARGET;
+        self.monolith_row(
+            (x >> 2) as f64, (z >> 2) as f64, (step/4) as f64, out,
+            |sum, mul| {
+                if sum - mul > TARGET {return Some(false);}
+                if sum + mul < TARGET {return Some(true);}
+                None
+            },
+            |sum| sum < TARGET,
+        );
+    }
+
+    /// Same as `is_depth_monolith()` for blocks `step` apart along a row
+    #[inline(always)]
+    pub fn is_depth_monolith_row(&self, x: i32, z: i32, step: i32, out: &mut [bool]) {
+        const TARGET: f64 = DEPTH_TARGET;
+
+        // Note: Rows past the Far Lands overflow like Java's ints, point by point
+        let end = x as i64 + (out.len() as i64) * (step as i64);
+        if [x as i64, z as i64, end].iter().any(|v| (25 * v).abs() > i32::MAX as i64) {
+            for (i, out) in out.iter_mut().enumerate() {
+                *out = self.is_depth_monolith(x.wrapping_add((i as i32).wrapping_mul(step)), z);
+            }
+            return;
+        }
+
+        self.monolith_row(
+            (25 * x) as f64, (25 * z) as f64, (25 * step) as f64, out,
+            |sum, mul| {
+                if (sum.abs() + mul) < TARGET {return Some(false);}
+                if (sum.abs() - mul) > TARGET {return Some(true);}
+                None
+            },
+            |sum| sum.abs() > TARGET,
+        );
+    }
+
+    /// Decide points along a row with the same early exits after each octave,
+    /// sampling rows of octaves only between the outermost undecided points.
+    /// Longer rows are decided `ROW_SAMPLES` points at a time
+    #[inline(always)]
+    fn monolith_row(&self,
+        x0: f64, z: f64, step: f64,
+        out: &mut [bool],
+        exit: impl Fn(f64, f64) -> Option<bool>,
+        last: impl Fn(f64) -> bool,
+    ) {
+        for (k, out) in out.chunks_mut(ROW_SAMPLES).enumerate() {
+            let x0 = x0 + (k * ROW_SAMPLES) as f64 * step;
+            let mut sums = [0.0; ROW_SAMPLES];
+            let mut decided = [None; ROW_SAMPLES];
+            let (mut lo, mut hi) = (0, out.len());
+
+            for octave in (S::OCTAVES_START..OCTAVES).rev() {
+                if lo == hi {
+                    break;
+                }
+
+                let mul = Self::octave_scale_mul_f64(octave);
+                let div = Self::octave_scale_div_f64(octave);
+                let start = (x0 + (lo as f64) * step) * div;
+                self.noise[octave].sample_row::<S>(start, z*div, step*div, mul, &mut sums[lo..hi]);
+
+                for i in lo..hi {
+                    if decided[i].is_none() {
+                        decided[i] = exit(sums[i], mul);
+                    }
+                }
+
+                while lo < hi && decided[lo].is_some() {lo += 1;}
+                while lo < hi && decided[hi-1].is_some() {hi -= 1;}
+            }
+
+            for (i, out) in out.iter_mut().enumerate() {
+                *out = decided[i].unwrap_or_else(|| last(sums[i]));
+            }
+        }
+    }
 }
@@ -0,0 +1,233 @@
+use crate::*;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::Range;
+
+/// Probes within a tile small enough to not bother bounding the noises
+const TILE_PROBES: usize = 16;
+
+/// Blocks across a tile large enough to rarely prove anything on the noises
+const TILE_BLOCKS: i64 = 512;
+
+/// Steps across a tile bounded anyway, so sparse searches still skip some,
+/// up to about 400 blocks apart where no such tile is ever narrow enough
+const TILE_STEPS: i64 = 8;
+
+/// Columns of probes each thread works on at a time
+const TILE_STRIP: usize = 64;
+
+/// A tile of a search, as ranges of probe indices along x and z
+pub type Tile = (Range<usize>, Range<usize>);
+
+/// A tile or a member probe, by its squared distance to the centre of a search
+type Ring = Reverse<(u128, bool, [usize; 4])>;
+
+/// Lazily yields the probes of a search that are monoliths, skipping tiles
+/// of it where none provably are
+pub struct Probes<'w, S: Sampling = Compiled> {
+    world: &'w World<S>,
+    region: Option<&'w Region>,
+    origin: (i32, i32),
+    step: i32,
+
+    /// Tiles left to probe, the next one last
+    tiles: Vec<Tile>,
+
+    /// Members of the last tile probed, the next one last
+    members: Vec<(i32, i32)>,
+
+    /// Tiles and members nearest to this block first, when searching outwards
+    centre: Option<(i64, i64)>,
+    rings: BinaryHeap<Ring>,
+
+    /// Probes skipped so far, within tiles where none can be monoliths
+    pub pruned: usize,
+}
+
+impl<'w, S: Sampling> Probes<'w, S> {
+
+    /// Probes within a tile of a search
+    pub fn new(world: &'w World<S>, query: &'w FindOptions, tile: Tile) -> Self {
+        let mut this = Self {
+            world,
+            region: query.region.as_ref(),
+            origin: (query.minx, query.minz),
+            step: query.step as i32,
+            tiles: Vec::new(),
+            members: Vec::new(),
+            centre: query.nearest.then_some(query.centre()),
+            rings: BinaryHeap::new(),
+            pruned: 0,
+        };
+        this.push(tile);
+        this
+    }
+
+    /// All probes of a search
+    pub fn search(world: &'w World<S>, query: &'w FindOptions) -> Self {
+        Self::new(world, query, (0..Probes::count(query.minx, query.maxx, query.step),
+                                 0..Probes::count(query.minz, query.maxz, query.step)))
+    }
+
+    #[inline(always)]
+    fn at(&self, origin: i32, index: usize) -> i32 {
+        (origin as i64 + (index as i64) * (self.step as i64)) as i32
+    }
+
+    /// Squared distance from the centre to the nearest block of a tile
+    fn distance(&self, (xs, zs): &Tile) -> u128 {
+        let (cx, cz) = self.centre.unwrap_or_default();
+        let axis = |centre: i64, min: i32, max: i32| {
+            (min as i64 - centre).max(centre - max as i64).max(0) as u128
+        };
+        let dx = axis(cx, self.at(self.origin.0, xs.start), self.at(self.origin.0, xs.end - 1));
+        let dz = axis(cz, self.at(self.origin.1, zs.start), self.at(self.origin.1, zs.end - 1));
+        dx*dx + dz*dz
+    }
+
+    /// Queue a tile to be probed, or a member of one to be yielded
+    fn queue(&mut self, tile: Tile, member: bool) {
+        match self.centre {
+            None => match member {
+                true  => self.members.push((self.at(self.origin.0, tile.0.start), self.at(self.origin.1, tile.1.start))),
+                false => self.tiles.push(tile),
+            },
+            Some(_) => {
+                let distance = self.distance(&tile);
+                let (xs, zs) = tile;
+                self.rings.push(Reverse((distance, !member, [xs.start, xs.end, zs.start, zs.end])));
+            }
+        }
+    }
+
+    fn push(&mut self, tile: Tile) {
+        if !tile.0.is_empty() && !tile.1.is_empty() {
+            self.queue(tile, false);
+        }
+    }
+
+    /// Split large tiles into quadrants, unless none of it can be a
+    /// monolith, or find the members of small ones by rows
+    fn probe(&mut self, (xs, zs): Tile) {
+        let (minx, maxx) = (self.at(self.origin.0, xs.start), self.at(self.origin.0, xs.end - 1));
+        let (minz, maxz) = (self.at(self.origin.1, zs.start), self.at(self.origin.1, zs.end - 1));
+
+        if self.region.is_some_and(|region| !region.overlaps(minx, maxx, minz, maxz)) {
+            return;
+        }
+
+        if xs.len() * zs.len() > TILE_PROBES {
+            let span = (maxx as i64 - minx as i64).max(maxz as i64 - minz as i64);
+
+            // Sparse searches bound wider tiles to ever have enough probes
+            if span <= TILE_BLOCKS.max(TILE_STEPS * self.step as i64)
+                && !self.world.may_have_monolith(minx, maxx, minz, maxz) {
+                self.pruned += xs.len() * zs.len();
+                return;
+            }
+
+            let xm = xs.start + xs.len()/2;
+            let zm = zs.start + zs.len()/2;
+
+            // Reversed to be popped in order
+            self.push((xm..xs.end, zm..zs.end));
+            self.push((xm..xs.end, zs.start..zm));
+            self.push((xs.start..xm, zm..zs.end));
+            self.push((xs.start..xm, zs.start..zm));
+            return;
+        }
+
+        // Probe small tiles by rows, evenly spaced
+        let mut row = [false; TILE_PROBES];
+        let row = &mut row[..xs.len()];
+
+        for i in zs.rev() {
+            let z = self.at(self.origin.1, i);
+            self.world.monolith_row(minx, z, self.step, row);
+
+            for (j, &member) in row.iter().enumerate().rev() {
+                if member && self.region.is_none_or(|region| region.contains(self.at(minx, j), z)) {
+                    let j = xs.start + j;
+                    self.queue((j..j+1, i..i+1), true);
+                }
+            }
+        }
+    }
+}
+
+impl Probes<'_> {
+
+    /// Tiles of a search threads can work on independently
+    pub fn strips(query: &FindOptions) -> Vec<Tile> {
+        let xs = Self::count(query.minx, query.maxx, query.step);
+        let zs = Self::count(query.minz, query.maxz, query.step);
+
+        (0..xs).step_by(TILE_STRIP)
+            .map(|x| (x..(x + TILE_STRIP).min(xs), 0..zs))
+            .collect()
+    }
+
+    /// Probes every `step` blocks from `min` up to `max`
+    fn count(min: i32, max: i32, step: usize) -> usize {
+        match max >= min {
+            true  => ((max as i64 - min as i64) as usize) / step + 1,
+            false => 0,
+        }
+    }
+}
+
+impl<S: Sampling> Iterator for Probes<'_, S> {
+    type Item = (i32, i32);
+
+    fn next(&mut self) -> Option<(i32, i32)> {
+        loop {
+            if let Some(member) = self.members.pop() {
+                return Some(member);
+            }
+
+            // Nearest tiles first, members before tiles as far
+            if self.centre.is_some() {
+                let Reverse((_, tile, [x0, x1, z0, z1])) = self.rings.pop()?;
+                match tile {
+                    true  => self.probe((x0..x1, z0..z1)),
+                    false => return Some((self.at(self.origin.0, x0), self.at(self.origin.1, z0))),
+                }
+                continue;
+            }
+
+            let tile = self.tiles.pop()?;
+            self.probe(tile);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Sparse searches still skip tiles, without losing any member
+    #[test]
+    fn sparse() {
+        let mut world = World::new();
+        world.init(617);
+
+        let step = 200;
+        let query = FindOptions::default().spawn(40000).step(step);
+        let mut probes = Probes::search(&world, &query);
+        let mut members: Vec<_> = probes.by_ref().collect();
+
+        let xs = Probes::count(query.minx, query.maxx, step);
+        let zs = Probes::count(query.minz, query.maxz, step);
+        let mut all: Vec<_> = (0..xs).flat_map(|i| (0..zs).map(move |j| (i, j)))
+            .map(|(i, j)| (query.minx + (i*step) as i32, query.minz + (j*step) as i32))
+            .filter(|&(x, z)| world.is_monolith(x, z))
+            .collect();
+
+        members.sort();
+        all.sort();
+        assert!(probes.pruned > 0);
+        assert_eq!(members, all);
+    }
+}
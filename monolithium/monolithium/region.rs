@@ -0,0 +1,264 @@
+use crate::*;
+
+/// An area of the world to search, in blocks, edges included
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all="lowercase")]
+pub enum Shape {
+    Rect {minx: i32, maxx: i32, minz: i32, maxz: i32},
+
+    /// Blocks at most `radius` away from a centre, by euclidean distance
+    Circle {x: i32, z: i32, radius: i32},
+
+    /// Blocks between `inner` and `outer` away from a centre
+    Annulus {x: i32, z: i32, inner: i32, outer: i32},
+
+    /// Blocks inside a closed outline, by the even-odd rule
+    Polygon {points: Vec<(i32, i32)>},
+}
+
+impl Shape {
+
+    /// Smallest rectangle containing the shape, as (minx, maxx, minz, maxz)
+    pub fn bounds(&self) -> (i32, i32, i32, i32) {
+        let around = |x: i32, z: i32, radius: i32| {
+            let radius = radius.saturating_abs();
+            (x.saturating_sub(radius), x.saturating_add(radius),
+             z.saturating_sub(radius), z.saturating_add(radius))
+        };
+
+        match self {
+            Self::Rect {minx, maxx, minz, maxz} => (*minx, *maxx, *minz, *maxz),
+            Self::Circle {x, z, radius} => around(*x, *z, *radius),
+            Self::Annulus {x, z, outer, ..} => around(*x, *z, *outer),
+            Self::Polygon {points} => points.iter().fold(
+                (i32::MAX, i32::MIN, i32::MAX, i32::MIN),
+                |(minx, maxx, minz, maxz), &(x, z)| {
+                    (minx.min(x), maxx.max(x), minz.min(z), maxz.max(z))
+                }),
+        }
+    }
+
+    /// Squared distances from a centre to the nearest and farthest blocks of a rectangle
+    fn distances((x, z): (i32, i32), (minx, maxx, minz, maxz): (i32, i32, i32, i32)) -> (i64, i64) {
+        let axis = |centre: i32, min: i32, max: i32| {
+            let (min, max, centre) = (min as i64, max as i64, centre as i64);
+            ((min - centre).max(centre - max).max(0), (centre - min).abs().max((max - centre).abs()))
+        };
+        let (nx, fx) = axis(x, minx, maxx);
+        let (nz, fz) = axis(z, minz, maxz);
+        (nx*nx + nz*nz, fx*fx + fz*fz)
+    }
+
+    /// Whether a block is within the shape
+    pub fn contains(&self, x: i32, z: i32) -> bool {
+        match self {
+            Self::Polygon {points} => Self::winds(points, x, z),
+            _ => self.covers(x, x, z, z),
+        }
+    }
+
+    /// Whether any block of a rectangle may be within the shape, never
+    /// false when one is, but possibly true when none are
+    pub fn overlaps(&self, minx: i32, maxx: i32, minz: i32, maxz: i32) -> bool {
+        let (bx0, bx1, bz0, bz1) = self.bounds();
+        if minx > bx1 || maxx < bx0 || minz > bz1 || maxz < bz0 {
+            return false;
+        }
+
+        match self {
+            Self::Rect {..} | Self::Polygon {..} => true,
+            Self::Circle {x, z, radius} => {
+                let (near, _) = Self::distances((*x, *z), (minx, maxx, minz, maxz));
+                near <= (*radius as i64).pow(2)
+            },
+            Self::Annulus {x, z, inner, outer} => {
+                let (near, far) = Self::distances((*x, *z), (minx, maxx, minz, maxz));
+                near <= (*outer as i64).pow(2) && far >= (*inner as i64).pow(2)
+            },
+        }
+    }
+
+    /// Whether all blocks of a rectangle are within the shape, never
+    /// true when one isn't, but possibly false when all are
+    pub fn covers(&self, minx: i32, maxx: i32, minz: i32, maxz: i32) -> bool {
+        match self {
+            Self::Rect {minx: x0, maxx: x1, minz: z0, maxz: z1} => {
+                *x0 <= minx && maxx <= *x1 && *z0 <= minz && maxz <= *z1
+            },
+            Self::Circle {x, z, radius} => {
+                let (_, far) = Self::distances((*x, *z), (minx, maxx, minz, maxz));
+                far <= (*radius as i64).pow(2)
+            },
+            Self::Annulus {x, z, inner, outer} => {
+                let (near, far) = Self::distances((*x, *z), (minx, maxx, minz, maxz));
+                far <= (*outer as i64).pow(2) && near >= (*inner as i64).pow(2)
+            },
+
+            // All corners inside with no vertex poking in between them
+            Self::Polygon {points} => {
+                [(minx, minz), (minx, maxz), (maxx, minz), (maxx, maxz)].iter()
+                    .all(|&(x, z)| Self::winds(points, x, z))
+                && !points.iter().any(|&(x, z)| minx <= x && x <= maxx && minz <= z && z <= maxz)
+            },
+        }
+    }
+
+    /// Even-odd rule of a point in a polygon, with points on its edges inside
+    fn winds(points: &[(i32, i32)], x: i32, z: i32) -> bool {
+        let (x, z) = (x as i64, z as i64);
+        let mut inside = false;
+
+        for (i, &(ax, az)) in points.iter().enumerate() {
+            let (bx, bz) = points[(i + 1) % points.len()];
+            let (ax, az, bx, bz) = (ax as i64, az as i64, bx as i64, bz as i64);
+
+            // Sign of the point relative to the edge, zero when in line
+            let cross = (bx - ax) * (z - az) - (bz - az) * (x - ax);
+            if cross == 0 && ax.min(bx) <= x && x <= ax.max(bx) && az.min(bz) <= z && z <= az.max(bz) {
+                return true;
+            }
+
+            // Edges crossing the ray towards +x, half-open on z
+            if (az > z) != (bz > z) && (cross > 0) == (bz > az) {
+                inside = !inside;
+            }
+        }
+        inside
+    }
+}
+
+impl std::str::FromStr for Shape {
+    type Err = String;
+
+    /// Shapes as `rect:minx,maxx,minz,maxz`, `circle:x,z,radius`,
+    /// `annulus:x,z,inner,outer` or `polygon:x,z,x,z,...`
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (kind, values) = string.trim().split_once(':')
+            .ok_or(format!("Shape '{string}' is missing a 'kind:' prefix"))?;
+
+        let values = values.split(',')
+            .map(|value| value.trim().parse::<i32>())
+            .collect::<Result<Vec<i32>, _>>()
+            .map_err(|error| format!("Shape '{string}' has a bad number: {error}"))?;
+
+        match (kind.trim(), values.as_slice()) {
+            ("rect",    &[minx, maxx, minz, maxz]) => Ok(Self::Rect {minx, maxx, minz, maxz}),
+            ("circle",  &[x, z, radius])           => Ok(Self::Circle {x, z, radius}),
+            ("annulus", &[x, z, inner, outer])     => Ok(Self::Annulus {x, z, inner, outer}),
+            ("polygon", values) if values.len() >= 6 && values.len() % 2 == 0 => Ok(Self::Polygon {
+                points: values.chunks(2).map(|pair| (pair[0], pair[1])).collect(),
+            }),
+            _ => Err(format!("Shape '{string}' is not a rect, circle, annulus or polygon with matching values")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Blocks within any of the included shapes but none of the excluded ones
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Region {
+    #[serde(default)]
+    pub include: Vec<Shape>,
+
+    #[serde(default)]
+    pub exclude: Vec<Shape>,
+}
+
+impl Region {
+
+    /// Read a region from a json file
+    pub fn load(path: &std::path::Path) -> Self {
+        let content = std::fs::read_to_string(path)
+            .expect("Could not read region file");
+        serde_json::from_str(&content)
+            .expect("Could not parse Region from JSON")
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.include.is_empty() && self.exclude.is_empty()
+    }
+
+    /// Smallest rectangle containing all included shapes
+    pub fn bounds(&self) -> (i32, i32, i32, i32) {
+        self.include.iter().map(Shape::bounds).fold(
+            (i32::MAX, i32::MIN, i32::MAX, i32::MIN),
+            |(minx, maxx, minz, maxz), (x0, x1, z0, z1)| {
+                (minx.min(x0), maxx.max(x1), minz.min(z0), maxz.max(z1))
+            })
+    }
+
+    /// Whether a block is to be searched
+    pub fn contains(&self, x: i32, z: i32) -> bool {
+        self.include.iter().any(|shape| shape.contains(x, z))
+            && !self.exclude.iter().any(|shape| shape.contains(x, z))
+    }
+
+    /// Whether any block of a rectangle may be searched
+    pub fn overlaps(&self, minx: i32, maxx: i32, minz: i32, maxz: i32) -> bool {
+        self.include.iter().any(|shape| shape.overlaps(minx, maxx, minz, maxz))
+            && !self.exclude.iter().any(|shape| shape.covers(minx, maxx, minz, maxz))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Pruning rectangles must agree with the blocks within them
+    #[test]
+    fn shapes() {
+        let shapes: Vec<Shape> = [
+            "rect:-40,25,-10,60",
+            "circle:5,-3,37",
+            "annulus:-2,4,15,41",
+            "polygon:-50,-50,50,-50,0,0,50,50,-50,50",
+        ].iter().map(|shape| shape.parse().unwrap()).collect();
+
+        for shape in &shapes {
+            for (minx, minz, size) in [(-60, -60, 120), (-8, -8, 16), (30, 30, 9), (0, -45, 5), (-20, 10, 3)] {
+                let (maxx, maxz) = (minx + size, minz + size);
+                let blocks = (minx..=maxx).flat_map(|x| (minz..=maxz).map(move |z| shape.contains(x, z)));
+                let (some, all) = blocks.fold((false, true), |(some, all), inside| (some || inside, all && inside));
+
+                assert!(!some || shape.overlaps(minx, maxx, minz, maxz), "{shape:?} {minx} {minz}");
+                assert!(!shape.covers(minx, maxx, minz, maxz) || all, "{shape:?} {minx} {minz}");
+            }
+        }
+
+        assert!( shapes[1].contains(5, 34) && !shapes[1].contains(31, 24));
+        assert!(!shapes[2].contains(-2, 4) &&  shapes[2].contains(-2, 44));
+        assert!(!shapes[3].contains(10, 0) &&  shapes[3].contains(-10, 0) && shapes[3].contains(25, 25));
+        assert!("hexagon:1,2,3".parse::<Shape>().is_err());
+        assert!("circle:1,2".parse::<Shape>().is_err());
+    }
+
+    /// Searching a disc finds the monoliths of a square that cross it
+    #[test]
+    fn region() {
+        let mut world = World::new();
+        world.init(617);
+
+        let square = FindOptions::default().spawn(20000).step(64);
+        let disc = FindOptions::default().step(64).region(Region {
+            include: vec![Shape::Circle {x: 0, z: 0, radius: 20000}],
+            exclude: vec![Shape::Rect {minx: -3000, maxx: 3000, minz: -3000, maxz: 3000}],
+        });
+        assert_eq!((disc.minx, disc.maxx, disc.minz, disc.maxz), (-20000, 20000, -20000, 20000));
+
+        // The square's probes are known to find all of its monoliths
+        let region = disc.region.as_ref().unwrap();
+        let every: AHashSet<Monolith> = Probes::search(&world, &square)
+            .filter(|&(x, z)| region.contains(x, z))
+            .filter_map(|(x, z)| world.get_monolith(x, z, &square))
+            .filter(|mono| mono.area > 0)
+            .collect();
+
+        let found: AHashSet<Monolith> = world.monoliths(&disc).collect();
+        assert!(!found.is_empty());
+        assert!(found.len() < world.monoliths(&square).count());
+        assert_eq!(found, every);
+    }
+}
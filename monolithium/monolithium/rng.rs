@@ -55,9 +55,10 @@ impl JavaRNG {
 
     /// Rolls the state and returns N<=32 low bits
     #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
     pub fn next<const BITS: u8>(&mut self) -> u64 {
         self.step();
-        return (self.state >> (48 - BITS)) as u64;
+        return self.state >> (48 - BITS);
     }
 
     /// Returns a pseudo-random i32 in the range [0, max)
@@ -69,12 +70,23 @@ impl JavaRNG {
             return (max * take) >> 31;
         }
 
-        if cfg!(not(feature = "skip-rejection")) {
-            let limit = (1u64 << 31) - ((1u64 << 31) % max);
+        let limit = (1u64 << 31) - ((1u64 << 31) % max);
 
-            while take >= limit {
-                take = self.next::<31>();
-            }
+        while take >= limit {
+            take = self.next::<31>();
+        }
+
+        return take % max;
+    }
+
+    /// Returns a pseudo-random i32 in the range [0, max) never rejecting values,
+    /// exact when it's known none would be, see `Perlin::rejects()`
+    #[inline(always)]
+    pub fn next_i32_bound_unchecked(&mut self, max: u64) -> u64 {
+        let take = self.next::<31>();
+
+        if max.is_power_of_two() {
+            return (max * take) >> 31;
         }
 
         return take % max;
@@ -83,23 +95,188 @@ impl JavaRNG {
     /// Returns a pseudo-random f64 in the range [0, 1)
     #[inline(always)]
     pub fn next_f64(&mut self) -> f64 {
-        let high = (self.next::<26>() as u64) << 27;
-        let low  =  self.next::<27>() as u64;
+        let high = self.next::<26>() << 27;
+        let low  =  self.next::<27>();
         (high | low) as f64 * FI
     }
 
     /// Returns a pseudo-random f64 in the range [0, 256)
     #[inline(always)]
     pub fn next_f64_256(&mut self) -> f64 {
-        let high = (self.next::<26>() as u64) << 27;
-        let low  =  self.next::<27>() as u64;
+        let high = self.next::<26>() << 27;
+        let low  =  self.next::<27>();
         (high | low) as f64 * (256.0 * FI)
     }
+
+    /// Returns a pseudo-random i32 over all values, Java's `nextInt()`
+    #[inline(always)]
+    pub fn next_i32(&mut self) -> i32 {
+        self.next::<32>() as u32 as i32
+    }
+
+    /// Returns a pseudo-random i64, Java's `nextLong()`. Not all values are
+    /// reachable, as it's made of two 32-bit draws from a 48-bit state
+    #[inline(always)]
+    pub fn next_i64(&mut self) -> i64 {
+        let high = (self.next_i32() as i64) << 32;
+        let low  =  self.next_i32() as i64;
+        high.wrapping_add(low)
+    }
+
+    /// Returns a pseudo-random bool, Java's `nextBoolean()`
+    #[inline(always)]
+    pub fn next_bool(&mut self) -> bool {
+        self.next::<1>() != 0
+    }
+
+    /// Returns a pseudo-random f32 in the range [0, 1), Java's `nextFloat()`
+    #[inline(always)]
+    pub fn next_f32(&mut self) -> f32 {
+        self.next::<24>() as f32 / (1u32 << 24) as f32
+    }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// Maximum value in step_n and back_n
+/// A `JavaRNG` with the state `java.util.Random` keeps besides the generator's,
+/// kept apart so the bare generator stays small on the hot paths
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct JavaRandom {
+    pub rng: JavaRNG,
+
+    /// The second value of the last `next_gaussian()` pair, if unused
+    pub gaussian: Option<f64>,
+}
+
+impl JavaRandom {
+
+    pub fn from_seed(seed: u64) -> Self {
+        Self {rng: JavaRNG::from_seed(seed), gaussian: None}
+    }
+
+    /// Returns a normally distributed f64 with mean 0.0 and deviation 1.0, Java's
+    /// `nextGaussian()`. Values are made in pairs via the polar method, with the
+    /// second one cached for the next call, as with `java.util.Random`
+    pub fn next_gaussian(&mut self) -> f64 {
+        if let Some(gaussian) = self.gaussian.take() {
+            return gaussian;
+        }
+
+        loop {
+            let v1 = 2.0 * self.rng.next_f64() - 1.0;
+            let v2 = 2.0 * self.rng.next_f64() - 1.0;
+            let s = v1 * v1 + v2 * v2;
+
+            if s < 1.0 && s != 0.0 {
+                let multiplier = (-2.0 * strict_ln(s) / s).sqrt();
+                self.gaussian = Some(v2 * multiplier);
+                return v1 * multiplier;
+            }
+        }
+    }
+}
+
+impl std::ops::Deref for JavaRandom {
+    type Target = JavaRNG;
+
+    fn deref(&self) -> &JavaRNG {
+        &self.rng
+    }
+}
+
+impl std::ops::DerefMut for JavaRandom {
+    fn deref_mut(&mut self) -> &mut JavaRNG {
+        &mut self.rng
+    }
+}
+
+/// Natural logarithm matching Java's `StrictMath.log`, a port of fdlibm's
+/// `__ieee754_log`, as platform libms may differ in the last bit
+fn strict_ln(x: f64) -> f64 {
+    const LN2_HI: f64 = f64::from_bits(0x3FE62E42FEE00000);
+    const LN2_LO: f64 = f64::from_bits(0x3DEA39EF35793C76);
+    const TWO54:  f64 = f64::from_bits(0x4350000000000000);
+    const LG1: f64 = f64::from_bits(0x3FE5555555555593);
+    const LG2: f64 = f64::from_bits(0x3FD999999997FA04);
+    const LG3: f64 = f64::from_bits(0x3FD2492494229359);
+    const LG4: f64 = f64::from_bits(0x3FCC71C51D8E78AF);
+    const LG5: f64 = f64::from_bits(0x3FC7466496CB03DE);
+    const LG6: f64 = f64::from_bits(0x3FC39A09D078C69F);
+    const LG7: f64 = f64::from_bits(0x3FC2F112DF3E5244);
+
+    let high = |x: f64| (x.to_bits() >> 32) as i32;
+    let mut x = x;
+    let mut hx = high(x);
+    let lx = x.to_bits() as u32;
+    let mut k: i32 = 0;
+
+    // Zero, negative and subnormal numbers
+    if hx < 0x00100000 {
+        if ((hx & 0x7fffffff) as u32 | lx) == 0 {
+            return f64::NEG_INFINITY;
+        }
+        if hx < 0 {
+            return f64::NAN;
+        }
+        k -= 54;
+        x *= TWO54;
+        hx = high(x);
+    }
+
+    // Infinity or NaN
+    if hx >= 0x7ff00000 {
+        return x + x;
+    }
+
+    k += (hx >> 20) - 1023;
+    hx &= 0x000fffff;
+    let i = (hx + 0x95f64) & 0x100000;
+
+    // Normalize x or x/2
+    x = f64::from_bits(((hx | (i ^ 0x3ff00000)) as u64) << 32 | (x.to_bits() & 0xffffffff));
+    k += i >> 20;
+    let f = x - 1.0;
+    let dk = k as f64;
+
+    // |f| < 2**-20
+    if (0x000fffff & (2 + hx)) < 3 {
+        if f == 0.0 {
+            return dk * LN2_HI + dk * LN2_LO;
+        }
+        let r = f * f * (0.5 - 0.3333333333333333 * f);
+        if k == 0 {
+            return f - r;
+        }
+        return dk * LN2_HI - ((r - dk * LN2_LO) - f);
+    }
+
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    let r = t2 + t1;
+
+    if ((hx - 0x6147a) | (0x6b851 - hx)) > 0 {
+        let hfsq = 0.5 * f * f;
+        if k == 0 {
+            return f - (hfsq - s * (hfsq + r));
+        }
+        return dk * LN2_HI - ((hfsq - (s * (hfsq + r) + dk * LN2_LO)) - f);
+    }
+
+    if k == 0 {
+        return f - s * (f - r);
+    }
+    return dk * LN2_HI - ((s * (f - r) - dk * LN2_LO) - f);
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Maximum value in step_n and back_n table lookups
 pub static SKIP_TABLE_SIZE: usize = 2_usize.pow(15);
 
 /// Forward modular multiplication table
@@ -130,20 +307,97 @@ pub static SKIP_TABLE_BACK: [(u64, u64); SKIP_TABLE_SIZE] = {
     table
 };
 
+/// Squares an affine step (x -> a*x + c), doubling its distance
+const fn skip_square((mul, add): (u64, u64)) -> (u64, u64) {
+    (
+        mul.wrapping_mul(mul) & M,
+        add.wrapping_mul(mul).wrapping_add(add) & M,
+    )
+}
+
+/// Forward steps for every power of two distance, 2**i at index i
+pub static SKIP_POWERS_NEXT: [(u64, u64); 48] = {
+    let mut table = [(A, C); 48];
+    let mut i = 1;
+    while i < 48 {
+        table[i] = skip_square(table[i - 1]);
+        i += 1;
+    }
+    table
+};
+
+/// Backward steps for every power of two distance, 2**i at index i
+pub static SKIP_POWERS_BACK: [(u64, u64); 48] = {
+    let mut table = [(AI, CI); 48];
+    let mut i = 1;
+    while i < 48 {
+        table[i] = skip_square(table[i - 1]);
+        i += 1;
+    }
+    table
+};
+
 impl JavaRNG {
 
+    /// Compose the affine step of any distance from its powers of two.
+    /// The generator has a period of 2**48, so only the low bits matter
+    #[inline(always)]
+    pub fn skip_affine(n: u64, powers: &[(u64, u64); 48]) -> (u64, u64) {
+        let (mut mul, mut add) = (1u64, 0u64);
+        let mut n = n & M;
+
+        while n != 0 {
+            let (a_i, c_i) = powers[n.trailing_zeros() as usize];
+            mul = mul.wrapping_mul(a_i) & M;
+            add = add.wrapping_mul(a_i).wrapping_add(c_i) & M;
+            n &= n - 1;
+        }
+
+        (mul, add)
+    }
+
     /// Roll the state N times fast
     #[inline(always)]
-    pub fn step_n(&mut self, n: usize) {
-        let (a_n, c_n) = unsafe {SKIP_TABLE_NEXT.get_unchecked(n)};
-        self.state = (self.state.wrapping_mul(*a_n).wrapping_add(*c_n)) & M;
+    pub fn step_n(&mut self, n: u64) {
+        let (a_n, c_n) = match n < SKIP_TABLE_SIZE as u64 {
+            true  => unsafe {*SKIP_TABLE_NEXT.get_unchecked(n as usize)},
+            false => Self::skip_affine(n, &SKIP_POWERS_NEXT),
+        };
+        self.state = (self.state.wrapping_mul(a_n).wrapping_add(c_n)) & M;
     }
 
     /// Roll the state backwards N times fast
     #[inline(always)]
-    pub fn back_n(&mut self, n: usize) {
-        let (a_n, c_n) = unsafe {SKIP_TABLE_BACK.get_unchecked(n)};
-        self.state = (self.state.wrapping_mul(*a_n).wrapping_add(*c_n)) & M;
+    pub fn back_n(&mut self, n: u64) {
+        let (a_n, c_n) = match n < SKIP_TABLE_SIZE as u64 {
+            true  => unsafe {*SKIP_TABLE_BACK.get_unchecked(n as usize)},
+            false => Self::skip_affine(n, &SKIP_POWERS_BACK),
+        };
+        self.state = (self.state.wrapping_mul(a_n).wrapping_add(c_n)) & M;
+    }
+
+    /// Number of `step()` calls from one state to another, or None when either
+    /// isn't a 48-bit state. As the generator has a full period, every low k bits
+    /// repeat every 2**k steps, so the distance can be matched bit by bit
+    pub fn distance(from: u64, to: u64) -> Option<u64> {
+        if (from | to) > M {
+            return None;
+        }
+
+        let mut state = from;
+        let mut n = 0;
+
+        for (bit, &(a_n, c_n)) in SKIP_POWERS_NEXT.iter().enumerate() {
+            let mask = (2u64 << bit) - 1;
+
+            // A 2**bit jump keeps the lower bits and flips this one
+            if (state ^ to) & mask != 0 {
+                state = (state.wrapping_mul(a_n).wrapping_add(c_n)) & M;
+                n |= 1 << bit;
+            }
+        }
+
+        Some(n)
     }
 
     #[inline(always)]
@@ -161,9 +415,112 @@ impl JavaRNG {
 
 /* -------------------------------------------------------------------------- */
 
+/// Many independent generators as a structure of arrays, so that the same
+/// operation on every lane compiles down to SIMD instructions (AVX2 and such)
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub struct JavaRNGx<const LANES: usize> {
+    pub state: [u64; LANES],
+}
+
+pub type JavaRNGx4 = JavaRNGx<4>;
+pub type JavaRNGx8 = JavaRNGx<8>;
+
+impl<const LANES: usize> JavaRNGx<LANES> {
+
+    #[inline(always)]
+    pub fn from_seeds(seeds: [u64; LANES]) -> Self {
+        Self {state: seeds.map(|seed| (seed ^ A) & M)}
+    }
+
+    #[inline(always)]
+    pub fn from_states(states: [u64; LANES]) -> Self {
+        Self {state: states.map(|state| state & M)}
+    }
+
+    /// Get a scalar generator at a given lane's state
+    #[inline(always)]
+    pub fn lane(&self, lane: usize) -> JavaRNG {
+        JavaRNG::from_state(self.state[lane])
+    }
+
+    /// Roll all lanes' states
+    #[inline(always)]
+    pub fn step(&mut self) {
+        for state in &mut self.state {
+            *state = state.wrapping_mul(A).wrapping_add(C) & M;
+        }
+    }
+
+    /// Roll all lanes' states N times fast
+    #[inline(always)]
+    pub fn step_n(&mut self, n: u64) {
+        let mut one = JavaRNG::from_state(1);
+        let mut zero = JavaRNG::from_state(0);
+        one.step_n(n);
+        zero.step_n(n);
+
+        // Recover the affine step from its effect on 0 and 1
+        let c_n = zero.state;
+        let a_n = one.state.wrapping_sub(c_n) & M;
+
+        for state in &mut self.state {
+            *state = state.wrapping_mul(a_n).wrapping_add(c_n) & M;
+        }
+    }
+
+    /// Rolls all states and returns N<=32 low bits of each
+    #[inline(always)]
+    #[allow(clippy::should_implement_trait)]
+    pub fn next<const BITS: u8>(&mut self) -> [u64; LANES] {
+        self.step();
+        self.state.map(|state| state >> (48 - BITS))
+    }
+
+    /// Returns a pseudo-random i32 in the range [0, max) for each lane.
+    /// Lanes that would reject a value fall back to scalar code, diverging
+    /// from the others' position in their streams, which is harmless
+    #[inline(always)]
+    pub fn next_i32_bound(&mut self, max: u64) -> [u64; LANES] {
+        let mut take = self.next::<31>();
+
+        if max.is_power_of_two() {
+            return take.map(|take| (max * take) >> 31);
+        }
+
+        let limit = (1u64 << 31) - ((1u64 << 31) % max);
+
+        if take.iter().any(|&take| take >= limit) {
+            for (take, state) in take.iter_mut().zip(&mut self.state) {
+                while *take >= limit {
+                    *state = state.wrapping_mul(A).wrapping_add(C) & M;
+                    *take = *state >> 17;
+                }
+            }
+        }
+
+        // Values fit in 31 bits, narrower modulo vectorizes better
+        take.map(|take| ((take as u32) % (max as u32)) as u64)
+    }
+
+    /// Returns a pseudo-random f64 in the range [0, 256) for each lane
+    #[inline(always)]
+    pub fn next_f64_256(&mut self) -> [f64; LANES] {
+        let high = self.next::<26>();
+        let low  = self.next::<27>();
+        std::array::from_fn(|lane| {
+            ((high[lane] << 27) | low[lane]) as f64 * (256.0 * FI)
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[cfg(test)]
 mod tests {
     use crate::rng::SKIP_TABLE_SIZE;
+    use crate::rng::M;
     use crate::*;
 
     #[test]
@@ -176,7 +533,7 @@ mod tests {
 
         // Forward stepping
         for size in 0..SKIP_TABLE_SIZE {
-            skip_table.step_n(size);
+            skip_table.step_n(size as u64);
 
             for _ in 0..size {
                 sequential.step()
@@ -187,7 +544,7 @@ mod tests {
 
         // Backwards stepping
         for size in 0..SKIP_TABLE_SIZE {
-            skip_table.back_n(size);
+            skip_table.back_n(size as u64);
 
             for _ in 0..size {
                 sequential.back();
@@ -202,6 +559,82 @@ mod tests {
         assert_eq!(sequential, start);
     }
 
+    #[test]
+    fn skip_powers() {
+        const SEED: u64 = 0xC0FFEE;
+        let start = JavaRNG::from_seed(SEED);
+
+        // Just past the lookup table, against sequential
+        let mut sequential = start;
+        let mut skip_power = start;
+        for _ in 0..(SKIP_TABLE_SIZE + 12345) {
+            sequential.step();
+        }
+        skip_power.step_n((SKIP_TABLE_SIZE + 12345) as u64);
+        assert_eq!(skip_power, sequential);
+
+        // Large jumps must compose and undo each other
+        for (a, b) in [(1 << 20, 7), (123_456_789, 987_654_321), (1 << 47, (1 << 47) - 1)] {
+            let mut split = start;
+            split.step_n(a);
+            split.step_n(b);
+
+            let mut joint = start;
+            joint.step_n(a + b);
+            assert_eq!(split, joint);
+
+            joint.back_n(a + b);
+            assert_eq!(joint, start);
+        }
+
+        // Full period wraps around
+        let mut period = start;
+        period.step_n(1 << 48);
+        assert_eq!(period, start);
+        period.back_n(u64::MAX);
+        period.step_n(u64::MAX);
+        assert_eq!(period, start);
+    }
+
+    #[test]
+    fn distance() {
+        let start = JavaRNG::from_seed(617);
+
+        for n in [0, 1, 2, 262, 48*262 + 3, 1 << 31, 94116384388573, (1 << 48) - 1] {
+            let mut other = start;
+            other.step_n(n);
+            assert_eq!(JavaRNG::distance(start.state, other.state), Some(n));
+            assert_eq!(JavaRNG::distance(other.state, start.state), Some(((1 << 48) - n) & M));
+        }
+
+        // Not reachable by any 48-bit state
+        assert_eq!(JavaRNG::distance(1 << 48, 0), None);
+        assert_eq!(JavaRNG::distance(0, u64::MAX), None);
+    }
+
+    /// Lanes must match scalar generators, even when some of them rejects
+    #[test]
+    fn lanes() {
+        let seeds = [0, 256846, 419981, 140740248931398];
+        let mut lanes = JavaRNGx4::from_seeds(seeds);
+        let mut scalar = seeds.map(JavaRNG::from_seed);
+
+        for max in (1..=256).rev() {
+            let values = lanes.next_i32_bound(max);
+            for lane in 0..4 {
+                assert_eq!(values[lane], scalar[lane].next_i32_bound(max));
+            }
+        }
+
+        lanes.step_n(123_456_789);
+        let values = lanes.next_f64_256();
+        for lane in 0..4 {
+            scalar[lane].step_n(123_456_789);
+            assert_eq!(values[lane].to_bits(), scalar[lane].next_f64_256().to_bits());
+            assert_eq!(lanes.lane(lane), scalar[lane]);
+        }
+    }
+
     fn test_next_i32_bound_seed_perlin(seed: u64, known: [u64; 256]) {
         let mut rng = JavaRNG::from_seed(seed);
 
@@ -244,6 +677,99 @@ mod tests {
         self::test_next_i32_bound_seed_perlin(35192371993854, [47, 210, 223, 122, 186, 232, 144, 177, 30, 114, 179, 2, 21, 88, 17, 161, 194, 84, 18, 117, 85, 209, 132, 227, 193, 50, 135, 101, 13, 149, 146, 172, 27, 26, 47, 56, 185, 75, 59, 144, 78, 69, 5, 45, 9, 161, 108, 51, 189, 8, 184, 197, 166, 146, 7, 169, 197, 40, 45, 77, 27, 56, 3, 120, 50, 43, 7, 107, 88, 41, 21, 9, 182, 157, 98, 129, 6, 122, 80, 51, 98, 135, 143, 102, 84, 47, 57, 26, 58, 122, 111, 34, 78, 96, 53, 4, 2, 80, 145, 149, 99, 145, 33, 106, 81, 99, 81, 0, 123, 28, 41, 131, 71, 67, 14, 55, 39, 91, 9, 41, 104, 9, 64, 50, 115, 28, 14, 71, 122, 11, 103, 63, 9, 91, 74, 56, 50, 81, 27, 37, 88, 70, 78, 57, 59, 43, 38, 97, 93, 49, 3, 35, 20, 68, 29, 12, 29, 57, 46, 75, 66, 10, 36, 53, 76, 64, 71, 66, 76, 51, 78, 49, 14, 62, 17, 37, 57, 43, 62, 34, 50, 63, 10, 68, 12, 28, 35, 55, 10, 41, 15, 38, 57, 37, 32, 29, 48, 35, 0, 51, 43, 19, 50, 20, 51, 41, 21, 46, 5, 36, 5, 2, 26, 27, 11, 2, 12, 12, 17, 34, 23, 20, 1, 17, 13, 18, 2, 7, 21, 1, 12, 7, 12, 4, 17, 7, 2, 8, 14, 2, 3, 6, 1, 3, 9, 7, 4, 0, 2, 6, 1, 2, 3, 2, 0, 0]); // Index 87, 88
     }
 
+    /// Rejections must be spotted ahead, after the offsets draws
+    #[test]
+    fn perlin_rejects() {
+        let rejects = |seed: u64| {
+            let mut rng = JavaRNG::from_seed(seed);
+            rng.back_n(3*2);
+            Perlin::rejects(&rng)
+        };
+
+        assert!(!rejects(0));
+        for seed in [256846, 419981, 432938, 463898, 906297, 930327, 140740248931398] {
+            assert!(rejects(seed));
+        }
+    }
+
+    /// Checked shuffles and discards must match the exact ones, also when rejecting
+    #[test]
+    fn checked_rejection() {
+        let seeds = [0, 617, 256846, 419981, 432938, 463898, 906297, 140740248931398];
+        let start = |seed: u64| {
+            let mut rng = JavaRNG::from_seed(seed);
+            rng.back_n(3*2);
+            rng
+        };
+
+        for seed in seeds {
+            let (mut exact, mut checked) = (start(seed), start(seed));
+            let perlin = Perlin::from_rng(&mut exact, Rejection::Exact);
+            assert_eq!(Perlin::from_rng(&mut checked, Rejection::Checked).map, perlin.map);
+            assert_eq!(exact, checked);
+
+            let (mut exact, mut checked) = (start(seed), start(seed));
+            Perlin::discard(&mut exact, 2, Rejection::Exact);
+            Perlin::discard(&mut checked, 2, Rejection::Checked);
+            assert_eq!(exact, checked);
+        }
+
+        let mut exact = JavaRNGx::from_states(seeds.map(|seed| start(seed).state));
+        let mut checked = exact;
+        Perlin::discard_lanes(&mut exact, 2, Rejection::Exact);
+        Perlin::discard_lanes(&mut checked, 2, Rejection::Checked);
+        assert_eq!(exact.state, checked.state);
+    }
+
+    /// Source: java.util.Random on OpenJDK 17, `new Random(738)`
+    #[test]
+    fn next_java_api() {
+        let mut rng = JavaRNG::from_seed(738);
+        assert_eq!(std::array::from_fn(|_| rng.next_i64()), [
+            -5752655791143746075, 5215961522380008421, 4419561346199499954, 8859886880667925700,
+            -8149343459614409971, -390368235641047498, -1555841166157963207, 8969633400774850236,
+        ]);
+
+        let mut rng = JavaRNG::from_seed(738);
+        assert_eq!(std::array::from_fn(|_| rng.next_i32()), [
+            -1339394551, 1841858021, 1214435679, -2020545563, 1029009313, -414927694, 2062853165, 542833860,
+        ]);
+
+        let mut rng = JavaRNG::from_seed(738);
+        assert_eq!(std::array::from_fn(|_| rng.next_bool()), [
+            true, false, false, true, false, true, false, false, true, true, true, false, true, true, false, false,
+        ]);
+
+        let mut rng = JavaRNG::from_seed(738);
+        assert_eq!(std::array::from_fn(|_| rng.next_f32().to_bits()), [
+            0x3F302A76, 0x3EDB910A, 0x3E90C5A2, 0x3F0790EB, 0x3E7555BC, 0x3F6744B4, 0x3EF5E948, 0x3E016BF0,
+        ]);
+    }
+
+    /// Source: java.util.Random on OpenJDK 17, `new Random(738)`
+    #[test]
+    fn next_gaussian() {
+        let mut rng = JavaRandom::from_seed(738);
+
+        let generate = std::array::from_fn(|_| {
+            rng.next_gaussian().to_bits()
+        });
+
+        assert_eq!(generate, [
+            0x3FEF2DECFC891E62, 0xBFF2001AF8CADC85, 0xBFF9B71FC12EE901, 0xBFBF21E713D0D22A,
+            0x3FA76BDD335B769D, 0x3FD813E1AF615EC2, 0x3FEB73E6C1DA0238, 0xBF9D124B1D9F3DC2,
+            0xBFC10856D3046E81, 0x3FE141B6A2031D76, 0x3FF72EBE84E65422, 0x3FEEA1E136FBEAF6,
+            0x3FE7E654A05444B0, 0xBFF53A14BDC5845C, 0x3FE2FDF66D800D1B, 0x3FF9397CC37C74A0,
+        ]);
+
+        // The cached value survives other calls in between
+        let mut rng = JavaRandom::from_seed(-4962768465676381896_i64 as u64);
+        assert_eq!(rng.next_gaussian().to_bits(), 0xBFE55B2B42A77868);
+        assert_eq!(rng.next_i32(), 858409126);
+        assert_eq!(rng.next_gaussian().to_bits(), 0x3FEA22D60E645064);
+        assert_eq!(rng.next_gaussian().to_bits(), 0x3FC588E3861DB22E);
+    }
+
     /// Source: https://github.com/coderbot16/java-rand/blob/master/src/test_data.rs
     #[test]
     fn next_f64() {
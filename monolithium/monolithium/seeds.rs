@@ -1,12 +1,226 @@
 use crate::*;
 
+/// A world seed as players know it, before Java masks it down to 48 bits
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum UserSeed {
+    /// A signed `long`, as typed in numbers
+    Long(i64),
+
+    /// Any text, turned into a number with Java's `String.hashCode()`
+    Text(String),
+}
+
+impl UserSeed {
+
+    /// The `long` the game feeds into `java.util.Random`, where text that reads
+    /// as a number is used as-is, otherwise its (sign extended) hash code
+    pub fn long(&self) -> i64 {
+        match self {
+            Self::Long(long) => *long,
+            Self::Text(text) => text.parse::<i64>()
+                .unwrap_or_else(|_| Self::hash_code(text) as i64),
+        }
+    }
+
+    /// The 48-bit equivalence class, as the LCG only keeps the lower bits
+    pub fn seed(&self) -> Seed {
+        (self.long() as u64) & rng::M
+    }
+
+    /// Java's `String.hashCode()`, over UTF-16 code units
+    pub fn hash_code(text: &str) -> i32 {
+        text.encode_utf16().fold(0i32, |hash, unit| {
+            hash.wrapping_mul(31).wrapping_add(unit as i32)
+        })
+    }
+
+    /// Whether this is just the 48-bit seed written as a number
+    pub fn is_plain(&self) -> bool {
+        matches!(self, Self::Long(long) if (*long as u64) == self.seed())
+    }
+
+    /// Lenient serde for a 48-bit seed from negative numbers or text
+    pub fn deserialize_seed<'de, D>(deserializer: D) -> Result<Seed, D::Error>
+    where D: serde::Deserializer<'de> {
+        Self::deserialize(deserializer).map(|user| user.seed())
+    }
+}
+
+impl std::str::FromStr for UserSeed {
+    type Err = std::convert::Infallible;
+
+    /// Numbers are longs (unsigned ones wrap around), quoted or any
+    /// other input is text, the same way the world creation screen does
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+
+        if let Some(text) = string.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+            return Ok(Self::Text(text.to_string()));
+        }
+
+        if let Ok(long) = string.parse::<i64>() {
+            return Ok(Self::Long(long));
+        }
+
+        if let Ok(long) = string.parse::<u64>() {
+            return Ok(Self::Long(long as i64));
+        }
+
+        Ok(Self::Text(string.to_string()))
+    }
+}
+
+impl std::fmt::Display for UserSeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Long(long) => write!(f, "{long}"),
+            Self::Text(text) => write!(f, "\"{text}\""),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Characters tried for text seeds, in order
+const ALPHANUMERIC: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Friendlier 64-bit seeds that generate the same world as a 48-bit one, as
+/// the upper 16 bits are lost in `java.util.Random`'s scrambling
+#[derive(Debug, Default, Serialize)]
+pub struct Equivalents {
+    /// Seeds that `new Random().nextLong()` can return, as random worlds have
+    pub next_long: Vec<i64>,
+
+    /// Seeds within i32 range, also reachable through text hash codes
+    pub int: Option<i32>,
+
+    /// Short alphanumeric text seeds, which aren't read as numbers
+    pub text: Vec<String>,
+}
+
+impl Equivalents {
+
+    /// Find the equivalents of a 48-bit seed, with text seeds up to `length` long
+    pub fn find(seed: Seed, length: usize) -> Self {
+        let seed = seed & rng::M;
+        let mut this = Self::default();
+
+        // The low 32 bits are the second draw, guess the hidden 16 bits of it
+        let low = seed & 0xFFFF_FFFF;
+        for hidden in 0..(1u64 << 16) {
+            let mut rng = JavaRNG::from_state((low << 16) | hidden);
+            rng.back();
+            rng.back();
+
+            let long = rng.next_i64();
+            if (long as u64) & rng::M == seed {
+                this.next_long.push(long);
+            }
+        }
+        this.next_long.sort();
+
+        // Positive or sign extended negative ints
+        this.int = match seed {
+            seed if seed <= i32::MAX as u64 => Some(seed as i32),
+            seed if seed >= (rng::M + 1) - (1 << 31) => Some((seed as i64 - (1 << 48)) as i32),
+            _ => None,
+        };
+
+        if let Some(int) = this.int {
+            for size in 1..=length {
+                this.text.extend(Self::texts(int, size));
+            }
+        }
+
+        this
+    }
+
+    /// Alphanumeric texts of a given size with a hash code, meeting in the
+    /// middle: hash(prefix + suffix) = hash(prefix) * 31**len(suffix) + hash(suffix)
+    fn texts(target: i32, size: usize) -> Vec<String> {
+        let tail = size.min(3);
+        let head = size - tail;
+
+        let spell = |mut index: u64, size: usize| -> String {
+            let mut text = vec![b'0'; size];
+            for char in text.iter_mut().rev() {
+                *char = ALPHANUMERIC[(index % 62) as usize];
+                index /= 62;
+            }
+            String::from_utf8(text).unwrap()
+        };
+
+        // All suffixes sorted by hash code
+        let mut suffixes: Vec<(i32, u64)> = (0..62u64.pow(tail as u32))
+            .map(|index| (UserSeed::hash_code(&spell(index, tail)), index))
+            .collect();
+        suffixes.sort_unstable();
+
+        let shift = 31i32.wrapping_pow(tail as u32);
+        let mut texts = Vec::new();
+
+        for index in 0..62u64.pow(head as u32) {
+            let prefix = spell(index, head);
+            let needed = target.wrapping_sub(UserSeed::hash_code(&prefix).wrapping_mul(shift));
+            let start = suffixes.partition_point(|&(hash, _)| hash < needed);
+
+            for &(_, suffix) in suffixes[start..].iter().take_while(|&&(hash, _)| hash == needed) {
+                let text = prefix.clone() + &spell(suffix, tail);
+
+                // Numbers are read as longs ingame
+                if text.parse::<i64>().is_err() {
+                    texts.push(text);
+                }
+            }
+        }
+
+        texts
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// One of `count` even parts of the seeds a factory makes, as `index/count`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SmartDefault, Serialize, Deserialize)]
+pub struct Shard {
+    pub index: u64,
+
+    #[default(1)]
+    pub count: u64,
+}
+
+impl std::str::FromStr for Shard {
+    type Err = String;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (index, count) = string.split_once('/')
+            .ok_or(format!("Shard '{string}' is not as index/count"))?;
+        let index = index.trim().parse::<u64>().map_err(|error| error.to_string())?;
+        let count = count.trim().parse::<u64>().map_err(|error| error.to_string())?;
+
+        match index < count {
+            true  => Ok(Self {index, count}),
+            false => Err(format!("Shard index must be below the count in '{string}'")),
+        }
+    }
+}
+
+impl std::fmt::Display for Shard {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[derive(clap::Subcommand)]
 pub enum SeedFactory {
 
-    /// Search in a specific seed value
+    /// Search in a specific seed value, signed or text
     Seed {
-        #[arg(short='v', long, default_value_t=0)]
-        value: Seed,
+        #[arg(short='v', long, default_value="0", allow_negative_numbers=true)]
+        value: UserSeed,
     },
 
     /// Search in N sequential seeds from a starting point
@@ -31,12 +245,9 @@ pub enum SeedFactory {
         #[arg(short='t', long, default_value_t=1_000_000)]
         total: u64,
 
-        /// Seed for the shared random generator
+        /// Seed for the shared random generator, the n-th seed is its n-th state
         #[arg(short='s', long)]
         seed: Option<Seed>,
-
-        #[arg(skip)]
-        rng: Arc<Mutex<JavaRNG>>,
     },
 
     /// Search in a fraction of all possible seeds
@@ -46,11 +257,13 @@ pub enum SeedFactory {
         ratio: f64,
     },
 
-    /// Search in a file with a list of seeds or monoliths
+    /// Search in a file with a list of seeds, quoted text seeds or monoliths
     File {
         #[arg(short='i', long)]
         input: String,
-        values: Vec<Seed>,
+
+        #[arg(allow_negative_numbers=true)]
+        values: Vec<UserSeed>,
     }
 }
 
@@ -67,20 +280,29 @@ impl SeedFactory {
 
                     // From a piped monoliths json
                     if line.starts_with('{') {
-                        let monolith = serde_json::from_str::<Monolith>(line)
+                        #[derive(Deserialize)]
+                        struct Entry {seed: UserSeed, user_seed: Option<UserSeed>}
+
+                        let entry = serde_json::from_str::<Entry>(line)
                             .expect("Could not parse Monolith from JSON");
-                        values.push(monolith.seed);
+                        values.push(entry.user_seed.unwrap_or(entry.seed));
 
-                    // Try parsing as number
-                    } else if let Ok(seed) = line.parse::<Seed>() {
-                        values.push(seed);
+                    // Quoted text or numbers, signed or not
+                    } else if line.starts_with('"')
+                        || line.parse::<i64>().is_ok()
+                        || line.parse::<u64>().is_ok()
+                    {
+                        values.push(line.parse().unwrap());
                     }
                 }
+
+                // Searching a world twice only finds the same monoliths
+                let mut seen = AHashSet::new();
+                values.retain(|value| seen.insert(value.seed()));
             },
 
-            Self::UniqueRandom { seed, rng, .. } => {
-                let seed = seed.unwrap_or_else(|| fastrand::u64(0..TOTAL_SEEDS));
-                *rng = Arc::new(Mutex::new(JavaRNG::from_state(seed)));
+            Self::UniqueRandom { seed, .. } => {
+                seed.get_or_insert_with(|| fastrand::u64(0..TOTAL_SEEDS));
             },
 
             _ => ()
@@ -102,7 +324,7 @@ impl SeedFactory {
     pub fn get(&self, n: u64) -> Seed {
         match self {
             Self::Seed{value} =>
-                *value,
+                value.seed(),
 
             Self::Linear{start, ..} =>
                 (*start + n) as Seed,
@@ -110,9 +332,9 @@ impl SeedFactory {
             Self::FastRandom{..} =>
                 fastrand::u64(0..TOTAL_SEEDS),
 
-            Self::UniqueRandom { rng, .. } => {
-                let mut rng = rng.lock().unwrap();
-                rng.step();
+            Self::UniqueRandom { seed, .. } => {
+                let mut rng = JavaRNG::from_state(seed.unwrap_or_default());
+                rng.step_n(n + 1);
                 rng.state as Seed
             }
 
@@ -120,7 +342,143 @@ impl SeedFactory {
                 (n as f64 / *ratio) as Seed,
 
             Self::File{values, ..} =>
-                values[n as usize],
+                values[n as usize].seed(),
+        }
+    }
+
+    /// Indices of the seeds in a shard. All of them split the same seeds with
+    /// no overlap, random ones too as their n-th seed is a jump ahead
+    pub fn shard(&self, shard: Shard) -> std::ops::Range<u64> {
+        let part = |index: u64| (self.total() as u128 * index as u128 / shard.count as u128) as u64;
+        part(shard.index)..part(shard.index + 1)
+    }
+
+    /// Which seeds these are, for others to tell if they search the same ones
+    pub fn identity(&self) -> String {
+        match self {
+            Self::Seed{value} => format!("seed {}", value.seed()),
+            Self::Linear{start, total} => format!("linear {start} {total}"),
+            Self::FastRandom{total} => format!("random {total}"),
+            Self::UniqueRandom{total, seed} => format!("urandom {total} {}", seed.unwrap_or_default()),
+            Self::Ratio{ratio} => format!("ratio {ratio}"),
+            Self::File{values, ..} => {
+                let mut hasher = std::hash::DefaultHasher::new();
+                values.iter().for_each(|value| value.seed().hash(&mut hasher));
+                format!("file {} {:016x}", values.len(), hasher.finish())
+            },
         }
     }
+
+    /// Seed of the shared random generator, drawing the same seeds again
+    pub fn rng_seed(&self) -> Option<Seed> {
+        match self {
+            Self::UniqueRandom{seed, ..} => *seed,
+            _ => None,
+        }
+    }
+
+    /// Draw the same seeds as a previous `rng_seed()` did
+    pub fn reseed(&mut self, value: Seed) {
+        if let Self::UniqueRandom{seed, ..} = self {
+            *seed = Some(value);
+        }
+    }
+
+    /// Seeds as the user wrote them, by their 48-bit class, when they differ
+    pub fn user_seeds(&self) -> AHashMap<Seed, UserSeed> {
+        let values = match self {
+            Self::Seed{value} => std::slice::from_ref(value),
+            Self::File{values, ..} => values.as_slice(),
+            _ => &[],
+        };
+
+        values.iter()
+            .filter(|user| !user.is_plain())
+            .map(|user| (user.seed(), user.clone()))
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn user_seeds() {
+        let parse = |string: &str| string.parse::<UserSeed>().unwrap();
+
+        assert_eq!(parse("617"), UserSeed::Long(617));
+        assert_eq!(parse("-1"), UserSeed::Long(-1));
+        assert_eq!(parse("hello"), UserSeed::Text("hello".into()));
+        assert_eq!(parse("\"617\""), UserSeed::Text("617".into()));
+
+        // Reference: Java's "hello".hashCode() and "Monolith".hashCode()
+        assert_eq!(parse("hello").long(), 99162322);
+        assert_eq!(parse("Monolith").long(), -258857420);
+
+        // Same world regardless of how it's written
+        assert_eq!(parse("-1").seed(), rng::M);
+        assert_eq!(parse("\"617\"").seed(), 617);
+        assert_eq!(parse("18446744073709551615").seed(), rng::M);
+        assert_eq!(parse(&(617 + (1i64 << 48)).to_string()).seed(), 617);
+        assert!(parse("617").is_plain());
+        assert!(!parse("-1").is_plain());
+    }
+
+    /// Shards split the seeds of a factory with no gaps nor overlaps
+    #[test]
+    fn shards() {
+        let seeds = SeedFactory::Linear {start: 617, total: 1003};
+        let mut next = 0;
+        for index in 0..7 {
+            let range = seeds.shard(format!("{index}/7").parse().unwrap());
+            assert_eq!(range.start, next);
+            next = range.end;
+        }
+        assert_eq!(next, 1003);
+        assert_eq!(seeds.shard(Shard::default()), 0..1003);
+        assert!("7/7".parse::<Shard>().is_err());
+        assert!("1-7".parse::<Shard>().is_err());
+    }
+
+    /// Random unique seeds are states of a generator, the same when reseeded
+    #[test]
+    fn unique_random() {
+        let mut seeds = SeedFactory::UniqueRandom {total: 100, seed: None};
+        seeds.initialize();
+        let drawn: Vec<Seed> = (0..100).map(|n| seeds.get(n)).collect();
+
+        let mut again = SeedFactory::UniqueRandom {total: 100, seed: None};
+        again.reseed(seeds.rng_seed().unwrap());
+        again.initialize();
+        assert!((0..100).rev().all(|n| again.get(n) == drawn[n as usize]));
+
+        let mut rng = JavaRNG::from_state(seeds.rng_seed().unwrap());
+        for &seed in &drawn {
+            rng.step();
+            assert_eq!(rng.state, seed);
+        }
+    }
+
+    #[test]
+    fn equivalents() {
+        // From a `new Random(738).nextLong()` world
+        let long = -5752655791143746075_i64;
+        let found = Equivalents::find(long as u64, 0);
+        assert!(found.next_long.contains(&long));
+        assert!(found.next_long.iter().all(|&other| (other as u64) & rng::M == (long as u64) & rng::M));
+        assert_eq!(found.int, None);
+
+        // Text seeds hash into ints
+        let found = Equivalents::find(99162322, 5);
+        assert_eq!(found.int, Some(99162322));
+        assert!(found.text.contains(&String::from("hello")));
+        assert!(found.text.iter().all(|text| UserSeed::hash_code(text) == 99162322));
+
+        // Negative ints are sign extended
+        let found = Equivalents::find((-258857420_i64 as u64) & rng::M, 0);
+        assert_eq!(found.int, Some(-258857420));
+    }
 }
@@ -0,0 +1,232 @@
+use crate::*;
+
+/// How permutation shuffles treat the draws `JavaRNG::next_i32_bound()` rejects
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Rejection {
+    /// Draw again like Java does
+    #[default]
+    Exact,
+
+    /// Check all draws of a noise at once first, drawing again only for such
+    Checked,
+
+    /// Never draw again, wrong for about 0.035% of the noises
+    Skip,
+}
+
+/// Accuracy and speed tradeoffs of a search, picked at runtime. Each one
+/// defaults to whether the Cargo feature of the same name is enabled
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SmartDefault, Serialize, Deserialize, clap::Args)]
+#[serde(default)]
+pub struct Strategy {
+
+    /// (Lossy  ) Ignore the lowest octaves of the noises, they contribute very little
+    #[default(cfg!(feature="most-octaves"))]
+    #[arg(long, default_value_t=cfg!(feature="most-octaves"))]
+    pub most_octaves: bool,
+
+    /// (Lossy  ) Assume shuffles of the noises never reject draws
+    #[default(cfg!(feature="skip-rejection"))]
+    #[arg(long, default_value_t=cfg!(feature="skip-rejection"))]
+    pub skip_rejection: bool,
+
+    /// (Speed  ) Skip shuffles of the noises after checking none of their draws reject
+    #[default(cfg!(feature="checked-rejection"))]
+    #[arg(long, default_value_t=cfg!(feature="checked-rejection"))]
+    pub checked_rejection: bool,
+
+    /// (Lossy  ) Skip the smoothing curve of the noises, areas are only correlated
+    #[default(cfg!(feature="linear-fade"))]
+    #[arg(long, default_value_t=cfg!(feature="linear-fade"))]
+    pub linear_fade: bool,
+
+    /// (Mode   ) Fill monoliths by growing a rectangle around them
+    #[default(cfg!(feature="linear-fill"))]
+    #[arg(long, default_value_t=cfg!(feature="linear-fill"))]
+    pub linear_fill: bool,
+
+    /// (Mode   ) Skip the depth noise, only yielding seed candidates with larger areas
+    #[default(cfg!(feature="only-hill"))]
+    #[arg(long, default_value_t=cfg!(feature="only-hill"))]
+    pub only_hill: bool,
+
+    /// (Mode   ) Skip seeds whose hill noise offsets aren't close to a half
+    #[default(cfg!(feature="filter-fracts"))]
+    #[arg(long, default_value_t=cfg!(feature="filter-fracts"))]
+    pub filter_fracts: bool,
+
+    /// (Mode   ) Also include the depth noise offsets with --filter-fracts
+    #[default(cfg!(feature="depth-fracts"))]
+    #[arg(long, default_value_t=cfg!(feature="depth-fracts"))]
+    pub depth_fracts: bool,
+
+    /// (Mode   ) Copy seeds to the generator state, instead of scrambling them
+    #[default(cfg!(feature="state-seed"))]
+    #[arg(long, default_value_t=cfg!(feature="state-seed"))]
+    pub state_seed: bool,
+
+    /// (Mode   ) Search sister worlds of each block's first seed, not the next seeds
+    #[default(cfg!(feature="sister-perlin"))]
+    #[arg(long, default_value_t=cfg!(feature="sister-perlin"))]
+    pub sister_perlin: bool,
+}
+
+impl Strategy {
+    pub const ONLY_HILL:    u8 = 1 << 0;
+    pub const MOST_OCTAVES: u8 = 1 << 1;
+    pub const LINEAR_FADE:  u8 = 1 << 2;
+
+    /// Sampling bits of the default strategy
+    pub const FEATURES: u8 =
+        (cfg!(feature="only-hill")    as u8 * Self::ONLY_HILL) |
+        (cfg!(feature="most-octaves") as u8 * Self::MOST_OCTAVES) |
+        (cfg!(feature="linear-fade")  as u8 * Self::LINEAR_FADE);
+
+    /// Tradeoffs deciding the innermost loops, as bits of a `Tuned` type
+    pub fn sampling(&self) -> u8 {
+        (self.only_hill    as u8 * Self::ONLY_HILL) |
+        (self.most_octaves as u8 * Self::MOST_OCTAVES) |
+        (self.linear_fade  as u8 * Self::LINEAR_FADE)
+    }
+
+    pub fn rejection(&self) -> Rejection {
+        match (self.skip_rejection, self.checked_rejection) {
+            (true, _)     => Rejection::Skip,
+            (false, true) => Rejection::Checked,
+            _             => Rejection::Exact,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Cutoffs of `World::good_perlin_fracts()`, on the deviations of each
+/// noise's offsets from a half, weighted per noise
+#[derive(Clone, Copy, Debug, PartialEq, SmartDefault, Serialize, Deserialize, clap::Args)]
+#[serde(default)]
+pub struct FractFilter {
+
+    /// (Fracts ) Largest weighted deviation of good seeds, 280 or 28000 with --depth-fracts
+    #[arg(long)]
+    pub quality: Option<f64>,
+
+    /// (Fracts ) Weight of the deviations of the hill noise offsets
+    #[default(1.0)]
+    #[arg(long, default_value_t=1.0)]
+    pub hill_weight: f64,
+
+    /// (Fracts ) Weight of the deviations of the depth noise offsets
+    #[default(1.0)]
+    #[arg(long, default_value_t=1.0)]
+    pub depth_weight: f64,
+}
+
+impl FractFilter {
+
+    /// The given quality, or the default one with or without the depth noise
+    pub fn quality(&self, depth: bool) -> f64 {
+        self.quality.unwrap_or(match depth {
+            true  => 28000.0,
+            false => 280.0,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Tradeoffs of the innermost sampling loops as constants, so that each
+/// combination of them is compiled on its own, see `specialize!`
+pub trait Sampling: Copy + Default + std::fmt::Debug + Send + Sync + 'static {
+    const BITS: u8;
+
+    /// Only the hill noise makes monoliths, without the depth one
+    const ONLY_HILL: bool = (Self::BITS & Strategy::ONLY_HILL) != 0;
+
+    /// Skip the smoothing curve in `Perlin::fade()`
+    const LINEAR_FADE: bool = (Self::BITS & Strategy::LINEAR_FADE) != 0;
+
+    /// Ignored count of lower octaves, as they contribute very little
+    const OCTAVES_START: usize = match (Self::BITS & Strategy::MOST_OCTAVES) != 0 {
+        true  => 3,
+        false => 0,
+    };
+
+    /// Distance in which the monoliths repeat, as only the hill noise
+    /// makes them without the depth one
+    const TORUS_WRAPS: i32 = match Self::ONLY_HILL {
+        true  => HILL_WRAPS,
+        false => MONOLITHS_REPEAT,
+    };
+}
+
+/// Sampling tradeoffs of a `Strategy` from its bits
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tuned<const BITS: u8>;
+
+impl<const BITS: u8> Sampling for Tuned<BITS> {
+    const BITS: u8 = BITS;
+}
+
+/// Sampling tradeoffs of the enabled Cargo features
+pub type Compiled = Tuned<{Strategy::FEATURES}>;
+
+/// Evaluate an expression with `$S` as the `Sampling` type of a strategy,
+/// matching its bits at runtime to one of all the types compiled
+#[macro_export]
+macro_rules! specialize {
+    ($strategy:expr, $S:ident => $body:expr) => {
+        $crate::seq!(N in 0..8 {
+            match $strategy.sampling() {
+                #(N => {type $S = $crate::Tuned<N>; $body},)*
+                _ => unreachable!(),
+            }
+        })
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    /// Strategies run the sampling type of their bits, defaulting to the features
+    #[test]
+    fn specialize() {
+        assert_eq!(Strategy::default().sampling(), Compiled::BITS);
+
+        for bits in 0..8 {
+            let strategy = Strategy {
+                only_hill:    (bits & Strategy::ONLY_HILL)    != 0,
+                most_octaves: (bits & Strategy::MOST_OCTAVES) != 0,
+                linear_fade:  (bits & Strategy::LINEAR_FADE)  != 0,
+                ..Default::default()
+            };
+            assert_eq!(specialize!(strategy, S => S::BITS), bits);
+            assert_eq!(World::<Tuned<0>>::with(strategy).is_ok(), bits == 0);
+        }
+    }
+
+    /// Lossy strategies still find the monoliths, differently so
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn strategies() {
+        let query = FindOptions::default();
+        let exact = Strategy {
+            most_octaves: false, skip_rejection: false, linear_fade: false,
+            linear_fill: false, only_hill: false, state_seed: false,
+            ..Default::default()
+        };
+
+        let area = |strategy: Strategy| specialize!(strategy, S => {
+            let mut world = World::<S>::with(strategy).unwrap();
+            world.init(617);
+            world.get_monolith(16080, 9424, &query).map(|mono| mono.area)
+        });
+
+        assert_eq!(area(exact), Some(169552));
+        assert!(area(Strategy {only_hill: true, ..exact}).unwrap() >= 169552);
+        assert!(area(Strategy {linear_fill: true, ..exact}).is_some());
+        assert_ne!(area(Strategy {linear_fade: true, ..exact}), Some(169552));
+    }
+}
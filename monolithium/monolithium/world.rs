@@ -1,20 +1,27 @@
 use crate::*;
+use std::cell::{Cell, RefCell};
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering::Relaxed;
 
 pub const SKIP_OCTAVES:  usize = 48;
 pub const HILL_OCTAVES:  usize = 10;
 pub const DEPTH_OCTAVES: usize = 16;
 
-/// Step by 4 blocks when calculating areas, as each noise coordinate is the world
-/// position divided by 4. This causes a small error in area calculation due to
-/// interpolation, but the average of over/under-shoots gives at most 1% error.
-static AREA_STEP: LazyLock<i32> = LazyLock::new(|| {
-    option_env!("AREA_STEP")
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(4)
-});
+/// Queued cells above which a parallel flood fill shares half with a new task
+const FLOOD_SPILL: usize = 1024;
+
+/// Blocks from spawn past which the depth noise coordinates overflow
+const DEPTH_LIMIT: i32 = i32::MAX / 25;
+
+/// Blocks across a rectangle past which the depth noise bounds never prove
+/// anything, as its unbounded higher frequencies alone swing past the target
+const DEPTH_BOUNDED: i64 = 32;
+
+/// Identity of a monolith without any member cell yet
+const NO_ID: (i32, i32) = (i32::MAX, i32::MAX);
 
 #[derive(Debug)]
-pub struct World {
+pub struct World<S: Sampling = Compiled> {
     pub seed: Seed,
 
     /// The internal pseudo random number generator
@@ -25,148 +32,600 @@ pub struct World {
     ///
     /// - This is the rarest of the two conditions
     ///
-    pub hill: FractalPerlin<HILL_OCTAVES>,
+    pub hill: FractalPerlin<HILL_OCTAVES, S>,
 
     /// Noise which modulates the hill factor's influence, with absolute values
     /// greater than 8000.0 being required to form a monolith.
     ///
     /// - About 40% of all blocks in any world satisfy this condition.
+    /// - Octaves are only shuffled once the hill check passes and samples them.
+    /// - Neither initialized nor sampled with `Sampling::ONLY_HILL`
     ///
-    #[cfg(not(feature="only-hill"))]
-    pub depth: FractalPerlin<DEPTH_OCTAVES>,
+    pub depth: FractalPerlin<DEPTH_OCTAVES, S>,
+
+    /// Tradeoffs of the world, the sampling ones being those of `S`
+    pub strategy: Strategy,
+}
+
+impl Default for World {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl World {
     pub fn new() -> Self {
-        World {
+        World::with(Strategy::default()).expect("Default strategy samples as compiled")
+    }
+}
+
+impl<S: Sampling> World<S> {
+
+    /// An empty world sampled as `S`, an error unless it matches the strategy's
+    /// sampling, so runtime ones should pick `S` with `specialize!`
+    pub fn with(strategy: Strategy) -> Result<Self, String> {
+        if strategy.sampling() != S::BITS {
+            return Err(format!("Strategy samples as bits {}, not the world's {}",
+                strategy.sampling(), S::BITS));
+        }
+        Ok(World {
             seed: 0,
             rng: JavaRNG::from_state(0),
             hill: FractalPerlin::new(),
-
-            #[cfg(not(feature="only-hill"))]
             depth: FractalPerlin::new(),
-        }
+            strategy,
+        })
     }
 
     #[inline(always)]
     pub fn init(&mut self, seed: Seed) {
+        let rejection = self.strategy.rejection();
         self.seed = seed;
 
-        if cfg!(feature="state-seed") {
+        if self.strategy.state_seed {
             self.rng = JavaRNG::from_state(seed);
         } else {
             self.rng = JavaRNG::from_seed(seed);
-            Perlin::discard(&mut self.rng, SKIP_OCTAVES);
+            Perlin::discard(&mut self.rng, SKIP_OCTAVES, rejection);
+        };
+
+        self.hill.init(&mut self.rng, rejection);
+
+        // Note: Octaves are only shuffled when first sampled
+        if !S::ONLY_HILL {
+            self.depth.init(&mut self.rng, rejection);
+        }
+    }
+
+    /// Initialize many worlds at once, advancing their generators in lockstep,
+    /// all with the strategy of the first one
+    #[inline(always)]
+    pub fn init_lanes<const LANES: usize>(worlds: &mut [Self; LANES], seeds: [Seed; LANES]) {
+        let strategy = worlds[0].strategy;
+        let rejection = strategy.rejection();
+        let mut rng: JavaRNGx<LANES>;
+
+        if strategy.state_seed {
+            rng = JavaRNGx::from_states(seeds);
+        } else {
+            rng = JavaRNGx::from_seeds(seeds);
+            Perlin::discard_lanes(&mut rng, SKIP_OCTAVES, rejection);
         };
 
-        self.hill.init(&mut self.rng);
+        FractalPerlin::init_lanes(worlds.each_mut().map(|world| &mut world.hill), &mut rng, rejection);
+
+        if !S::ONLY_HILL {
+            FractalPerlin::init_lanes(worlds.each_mut().map(|world| &mut world.depth), &mut rng, rejection);
+        }
 
-        #[cfg(not(feature="only-hill"))]
-        self.depth.init(&mut self.rng);
+        for (lane, world) in worlds.iter_mut().enumerate() {
+            world.seed = seeds[lane];
+            world.rng  = rng.lane(lane);
+        }
     }
 
     // Check if a given coordinate is part of a monolith
     #[inline(always)]
     pub fn is_monolith(&self, x: i32, z: i32) -> bool {
-        #[cfg(feature="only-hill")] {
+        if S::ONLY_HILL {
             self.hill.is_hill_monolith(x, z)
-        }
-
-        #[cfg(not(feature="only-hill"))] {
+        } else {
             self.hill.is_hill_monolith(x, z) &&
             self.depth.is_depth_monolith(x, z)
         }
     }
 
-    /// Get a Monolith at a given coordinate, compute properties
+    /// Same as `is_monolith()` on blocks `step` apart along a row, sampling the
+    /// noises a whole row at a time where hill coordinates are evenly spaced.
+    /// Short or sparse rows don't share enough lattice cells to be worth it
+    pub fn monolith_row(&self, x0: i32, z: i32, step: i32, out: &mut [bool]) {
+        // Rows far past the Far Lands would overflow the depth coordinates
+        let end = x0 as i64 + (out.len() as i64) * (step as i64);
+        let far = [x0 as i64, z as i64, end].iter().any(|v| v.abs() >= DEPTH_LIMIT as i64);
+
+        if step % 4 != 0 || step > 64 || out.len() < 4 || far {
+            for (i, out) in out.iter_mut().enumerate() {
+                *out = self.is_monolith(x0 + (i as i32)*step, z);
+            }
+            return;
+        }
+
+        for (k, out) in out.chunks_mut(ROW_SAMPLES).enumerate() {
+            let x0 = x0 + (k * ROW_SAMPLES) as i32 * step;
+            self.hill.is_hill_monolith_row(x0, z, step, out);
+
+            // Most rows have no hill candidates at all
+            if !S::ONLY_HILL && out.iter().any(|&out| out) {
+                let mut depth = [false; ROW_SAMPLES];
+                let depth = &mut depth[..out.len()];
+                self.depth.is_depth_monolith_row(x0, z, step, depth);
+
+                for (out, depth) in out.iter_mut().zip(depth) {
+                    *out &= *depth;
+                }
+            }
+        }
+    }
+
+    /// Get a Monolith at a given coordinate, compute properties. On a torus,
+    /// it is the repeat identified within the first `Sampling::TORUS_WRAPS` blocks
     #[inline(always)]
-    #[cfg(not(feature="linear-fill"))]
-    pub fn get_monolith(&self, x: i32, z: i32) -> Option<Monolith> {
+    pub fn get_monolith(&self, x: i32, z: i32, options: &FindOptions) -> Option<Monolith> {
 
         // Most blocks are not monoliths
         if !self.is_monolith(x, z) {
             return None;
         }
 
-        let step = *AREA_STEP;
+        Some(self.finish(self.fill(x, z, options, &Self::counting(options, (0, 0))), options))
+    }
+
+    /// Identify a filled monolith on the torus, and note how much of it the
+    /// Far Lands overwrite before clipping its coordinates at them
+    fn finish(&self, mut lith: Monolith, options: &FindOptions) -> Monolith {
+        if options.torus && lith.wrap(S::TORUS_WRAPS) != (0, 0) {
+            lith.inbounds = self.inbounds(&lith, options, (0, 0));
+        }
+        lith.clip_farlands();
+        lith
+    }
+
+    /// An empty monolith of this world, to be filled
+    fn monolith(&self) -> Monolith {
+        Monolith {
+            minx: 0, maxx: 0,
+            minz: 0, maxz: 0,
+            id: NO_ID,
+            seed: self.seed,
+            user_seed: None,
+            area: 0,
+            inbounds: 0,
+            clipped: false,
+            farlands: 0,
+            components: 0,
+        }
+    }
+
+    /// How many cells of a run along x, on a copy `shift` blocks away, are
+    /// before the Far Lands
+    #[inline(always)]
+    fn inbounds_run(l: i32, r: i32, cz: i32, (dx, dz): (i32, i32), step: i32) -> u64 {
+        let step = step as i64;
+        let far = FARLANDS as i64;
+        if (cz as i64 * step + dz as i64).abs() > far {
+            return 0;
+        }
+        let lo = (l as i64).max((-far - dx as i64 + step - 1).div_euclid(step));
+        let hi = (r as i64).min((far - dx as i64).div_euclid(step));
+        (hi - lo + 1).max(0) as u64
+    }
+
+    /// Counts the blocks of runs of member cells along x that are before the
+    /// Far Lands, on a copy `shift` blocks away
+    fn counting(options: &FindOptions, shift: (i32, i32)) -> impl Fn(&mut Monolith, i32, i32, i32) + Sync {
+        let step = options.area_step;
         let cell = (step * step) as u64;
-        let x = utils::nearest(x, step);
-        let z = utils::nearest(z, step);
-        let o = 32; // "Occasionally"
+        move |lith, l, r, cz| lith.inbounds += cell * Self::inbounds_run(l, r, cz, shift, step)
+    }
 
-        // Start with current block
+    /// Blocks of a monolith's copy `shift` blocks away that are before the
+    /// Far Lands, filling it again only when it comes close to them
+    fn inbounds(&self, lith: &Monolith, options: &FindOptions, shift: (i32, i32)) -> u64 {
+        let mut copy = lith.clone();
+        copy.shift(shift.0, shift.1);
+
+        // Note: Coordinates are only updated every stride blocks
+        if copy.farlands_distance() > options.stride {
+            return lith.area;
+        }
+
+        self.fill(lith.id.0, lith.id.1, options, &Self::counting(options, shift)).inbounds
+    }
+
+    /// Flood fill the monolith at a member block, calling `runs` on each run
+    /// of member cells along x, from its first to last cell
+    #[inline(always)]
+    fn fill(&self, x: i32, z: i32, options: &FindOptions, runs: &impl Fn(&mut Monolith, i32, i32, i32)) -> Monolith {
+        if self.strategy.linear_fill {
+            return self.linear_fill(x, z, options, runs);
+        }
+
+        let (mut lith, start, mut jumps) = self.flood_start(x, z, options);
+
+        FLOOD_GRID.with_borrow_mut(|grid| {
+            grid.clear();
+            match options.torus {
+                true  => self.flood_from(&mut Torus {cells: grid, period: S::TORUS_WRAPS / options.area_step}, start, &mut jumps, &mut lith, options, runs),
+                false => self.flood_from(grid, start, &mut jumps, &mut lith, options, runs),
+            }
+        });
+
+        lith
+    }
+
+    /// Same as `get_monolith()`, but large ones are filled on all threads,
+    /// unless filled linearly
+    pub fn par_get_monolith(&self, x: i32, z: i32, options: &FindOptions) -> Option<Monolith> {
+        if self.strategy.linear_fill {
+            return self.get_monolith(x, z, options);
+        }
+
+        // Most blocks are not monoliths
+        if !self.is_monolith(x, z) {
+            return None;
+        }
+
+        let grid = SharedFloodGrid::default();
+        let period = S::TORUS_WRAPS / options.area_step;
+
+        let lith = match options.torus {
+            true  => self.par_flood_from(x, z, options, &|| Torus {cells: grid.cursor(), period}),
+            false => self.par_flood_from(x, z, options, &|| grid.cursor()),
+        };
+
+        Some(self.finish(lith, options))
+    }
+
+    /// Claim the starting cell and fill from it on a grid, a component at a
+    /// time from each of the jumps landing on a new one
+    #[inline(always)]
+    fn flood_from(&self,
+        grid: &mut impl FloodCells,
+        start: Option<(i32, i32)>,
+        jumps: &mut Vec<(i32, i32)>,
+        lith: &mut Monolith,
+        options: &FindOptions,
+        runs: &impl Fn(&mut Monolith, i32, i32, i32),
+    ) {
+        if let Some(start) = start {
+            self.flood_claim(grid, start, lith, options);
+        }
+        let mut queue = Vec::new();
+
+        while let Some((cx, cz)) = jumps.pop() {
+            if grid.visited(cx, cz) {
+                continue;
+            }
+            let area = lith.area;
+            queue.push((cx, cz));
+            self.flood(grid, &mut queue, jumps, lith, options, runs, &mut |_| {});
+            lith.components += (lith.area > area) as u32;
+        }
+    }
+
+    /// Claim the starting cell and fill from it and all satellites on all threads,
+    /// with cursors on the same shared grid, telling components apart afterwards
+    fn par_flood_from<C: FloodCells>(&self, x: i32, z: i32, options: &FindOptions, cursor: &(dyn Fn() -> C + Sync)) -> Monolith {
+        let (mut lith, start, jumps) = self.flood_start(x, z, options);
+        let mut grid = cursor();
+        if let Some(start) = start {
+            self.flood_claim(&mut grid, start, &mut lith, options);
+        }
+
+        let lith = Mutex::new(lith);
+        let found = Mutex::new(Vec::new());
+        let runs = Self::counting(options, (0, 0));
+        rayon::scope(|scope| self.par_flood(scope, cursor, options, &lith, &found, jumps, &runs));
+
+        let mut lith = lith.into_inner().unwrap();
+        lith.components = Self::components(found.into_inner().unwrap(), options);
+        lith
+    }
+
+    /// Work stealing part of `par_get_monolith()`, spilling half of the queue
+    /// to a new task whenever it grows large, and draining satellites it finds
+    #[allow(clippy::too_many_arguments)]
+    fn par_flood<'s, C: FloodCells, R: Fn(&mut Monolith, i32, i32, i32) + Sync>(&'s self,
+        scope: &rayon::Scope<'s>,
+        cursor: &'s (dyn Fn() -> C + Sync),
+        options: &'s FindOptions,
+        total: &'s Mutex<Monolith>,
+        found: &'s Mutex<Vec<(i32, i32, i32)>>,
+        mut queue: Vec<(i32, i32)>,
+        runs: &'s R,
+    ) {
         let mut lith = Monolith {
+            minx: i32::MAX, minz: i32::MAX,
+            maxx: i32::MIN, maxz: i32::MIN,
+            ..self.monolith()
+        };
+
+        // Runs of this task, as (z, left, right) cells
+        let filled = RefCell::new(Vec::new());
+        let record = |lith: &mut Monolith, l, r, cz| {
+            runs(lith, l, r, cz);
+            filled.borrow_mut().push((cz, l, r));
+        };
+
+        let mut grid = cursor();
+        let mut satellites = Vec::new();
+        while !queue.is_empty() {
+            self.flood(&mut grid, &mut queue, &mut satellites, &mut lith, options, &record, &mut |queue| {
+                if queue.len() > FLOOD_SPILL {
+                    let half = queue.split_off(queue.len()/2);
+                    scope.spawn(move |scope| self.par_flood(scope, cursor, options, total, found, half, runs));
+                }
+            });
+            queue.append(&mut satellites);
+        }
+        found.lock().unwrap().append(&mut filled.into_inner());
+
+        let mut total = total.lock().unwrap();
+        total.area += lith.area;
+        total.inbounds += lith.inbounds;
+        total.minx = total.minx.min(lith.minx);
+        total.maxx = total.maxx.max(lith.maxx);
+        total.minz = total.minz.min(lith.minz);
+        total.maxz = total.maxz.max(lith.maxz);
+        if lith.id != NO_ID {
+            let step = options.area_step;
+            Self::identify(&grid, &mut total, (lith.id.0/step, lith.id.1/step), step);
+        }
+    }
+
+    /// Connected pieces of runs of member cells, joined like `flood()` joins cells.
+    /// The same as filling each piece from a jump, whichever tasks filled it
+    fn components(mut runs: Vec<(i32, i32, i32)>, options: &FindOptions) -> u32 {
+        let diagonal = (options.connectivity == 8) as i32;
+        let mut parent: Vec<usize> = (0..runs.len()).collect();
+        runs.sort_unstable();
+
+        fn root(parent: &mut [usize], mut i: usize) -> usize {
+            while parent[i] != i {
+                parent[i] = parent[parent[i]];
+                i = parent[i];
+            }
+            i
+        }
+        let mut join = |a: usize, b: usize| {
+            let (a, b) = (root(&mut parent, a), root(&mut parent, b));
+            parent[a] = b;
+        };
+
+        let mut last = 0..0;
+        let mut row = 0;
+        while row < runs.len() {
+            let cz = runs[row].0;
+            let end = row + runs[row..].iter().take_while(|run| run.0 == cz).count();
+
+            // Runs split between tasks touch along the row
+            for i in row..end-1 {
+                if runs[i].2 + 1 == runs[i+1].1 {
+                    join(i, i+1);
+                }
+            }
+
+            // Runs on the previous row overlap them, also by corners
+            if !last.is_empty() && runs[last.start].0 == cz - 1 {
+                let (mut i, mut j) = (last.start, row);
+                while i < last.end && j < end {
+                    let (_, l0, r0) = runs[i];
+                    let (_, l1, r1) = runs[j];
+                    if l0 <= r1 + diagonal && l1 <= r0 + diagonal {
+                        join(i, j);
+                    }
+                    if r0 < r1 {i += 1} else {j += 1}
+                }
+            }
+
+            last = row..end;
+            row = end;
+        }
+
+        (0..runs.len()).filter(|&i| root(&mut parent, i) == i).count() as u32
+    }
+
+    /// Keep the smallest member cell as wrapped on the grid, in blocks, to
+    /// identify a monolith the same way wherever it was filled from
+    #[inline(always)]
+    fn identify(grid: &impl FloodCells, lith: &mut Monolith, (cx, cz): (i32, i32), step: i32) {
+        if lith.id == NO_ID || grid.wrap(cx, cz) < grid.wrap(lith.id.0/step, lith.id.1/step) {
+            lith.id = (cx*step, cz*step);
+        }
+    }
+
+    /// Visit the starting cell, which is never counted but still identifies
+    #[inline(always)]
+    fn flood_claim(&self, grid: &mut impl FloodCells, (sx, sz): (i32, i32), lith: &mut Monolith, options: &FindOptions) {
+        let step = options.area_step;
+        grid.visit(sx, sz);
+        if self.is_monolith(sx*step, sz*step) {
+            Self::identify(grid, lith, (sx, sz), step);
+        }
+    }
+
+    /// Blocks between member cells doing occasional work, on the grid of cells
+    #[inline(always)]
+    fn occasionally(options: &FindOptions) -> i32 {
+        let step = options.area_step;
+        (options.stride/step).max(1) * step
+    }
+
+    /// Distances to look for satellites at, every 64 blocks up to the furthest
+    #[inline(always)]
+    fn satellites(options: &FindOptions) -> impl Iterator<Item=i32> {
+        let step = options.area_step;
+        (64..options.satellites).step_by(64)
+            .chain([options.satellites])
+            .filter(move |&n| n >= step)
+    }
+
+    /// A fresh monolith at a block, the cell to claim first if any and the
+    /// cells to jump to around it
+    #[inline(always)]
+    #[allow(clippy::type_complexity)]
+    fn flood_start(&self, x: i32, z: i32, options: &FindOptions) -> (Monolith, Option<(i32, i32)>, Vec<(i32, i32)>) {
+        let step = options.area_step;
+        let cell = ((x + step/2).div_euclid(step), (z + step/2).div_euclid(step));
+        let (x, z) = (cell.0 * step, cell.1 * step);
+        let o = Self::occasionally(options); // "Occasionally"
+
+        // Start with current block
+        let lith = Monolith {
             minx: (x+o), minz: (z+o),
             maxx: (x-o), maxz: (z-o),
-            seed: self.seed,
-            area: 0,
+            ..self.monolith()
         };
 
-        // Using a Breadth First Search like approach
-        let mut visited = AHashSet::from([(x, z)]);
-        let mut queue   = VecDeque::from([(x, z)]);
+        let (sx, sz) = cell;
 
-        // Search around the block
-        let far: i32 = 128;
-        for dx in (-far..=far).step_by(32) {
-            for dz in (-far..=far).step_by(32) {
-                queue.push_back((x+dx, z+dz));
+        // Without satellites, the piece of the nearest cell is all there is
+        let far = options.satellites/step;
+        if far == 0 {
+            return (lith, None, vec![cell]);
+        }
+
+        // Search around the block. Note: The starting cell itself is never counted
+        let mut jumps = Vec::new();
+        for dx in (-far..=far).step_by((o/step) as usize) {
+            for dz in (-far..=far).step_by((o/step) as usize) {
+                jumps.push((sx+dx, sz+dz));
             }
         }
 
-        while let Some((x, z)) = queue.pop_front() {
-            if !visited.insert((x, z)) {
+        (lith, Some((sx, sz)), jumps)
+    }
+
+    /// Scanline flood fill on a grid of cells one step apart, from the queued
+    /// ones, until their component is whole. Cells of nearby satellites are
+    /// left in `jumps`. Calls `spill` with the queue to optionally offload some of it
+    #[inline(always)]
+    #[allow(clippy::too_many_arguments)]
+    fn flood(&self,
+        grid: &mut impl FloodCells,
+        queue: &mut Vec<(i32, i32)>,
+        jumps: &mut Vec<(i32, i32)>,
+        lith: &mut Monolith,
+        options: &FindOptions,
+        runs: &impl Fn(&mut Monolith, i32, i32, i32),
+        spill: &mut impl FnMut(&mut Vec<(i32, i32)>),
+    ) {
+        let step = options.area_step;
+        let cell = (step * step) as u64;
+        let o = Self::occasionally(options);
+        let diagonal = (options.connectivity == 8) as i32;
+
+        while let Some((cx, cz)) = queue.pop() {
+            if !grid.visit(cx, cz) {
                 continue;
             }
-            if !self.is_monolith(x, z) {
+            if !self.is_monolith(cx*step, cz*step) {
                 continue;
             }
 
-            lith.area += cell;
-
-            // Check connected neighbors
-            queue.push_back((x+0, z+step));
-            queue.push_back((x+0, z-step));
-            queue.push_back((x+step, z+0));
-            queue.push_back((x-step, z+0));
-
-            // Occasional more expensive stuff
-            if (x % o == 0) && (z % o == 0) {
-
-                // Check for nearby satellites
-                for n in [64, 128] {
-                    queue.push_back((x+n, z+n));
-                    queue.push_back((x+n, z-n));
-                    queue.push_back((x-n, z+n));
-                    queue.push_back((x-n, z-n));
-                    queue.push_back((x+n, z+0));
-                    queue.push_back((x-n, z+0));
-                    queue.push_back((x+0, z+n));
-                    queue.push_back((x+0, z-n));
+            // Extend a run of connected cells along x
+            let l = self.flood_run(grid, cx, cz, -1, step);
+            let r = self.flood_run(grid, cx, cz,  1, step);
+            lith.area += cell * (r - l + 1) as u64;
+            runs(lith, l, r, cz);
+
+            // The run may cross a repeat of the grid
+            let (wl, wr) = (grid.wrap(l, cz).0, grid.wrap(r, cz).0);
+            Self::identify(grid, lith, (if wr < wl {r - wr} else {l}, cz), step);
+
+            // Check connected neighbors, also past the ends diagonally
+            for c in (l - diagonal)..=(r + diagonal) {
+                for n in [cz-1, cz+1] {
+                    if !grid.visited(c, n) {
+                        queue.push((c, n));
+                    }
                 }
+            }
 
-                // Update coordinates
-                lith.minx = lith.minx.min(x);
-                lith.maxx = lith.maxx.max(x);
-                lith.minz = lith.minz.min(z);
-                lith.maxz = lith.maxz.max(z);
+            for c in l..=r {
+
+                // Occasional more expensive stuff
+                let (x, z) = (c*step, cz*step);
+                if (x % o == 0) && (z % o == 0) {
+
+                    // Check for nearby satellites
+                    for n in Self::satellites(options) {
+                        let n = n/step;
+                        jumps.push((c+n, cz+n));
+                        jumps.push((c+n, cz-n));
+                        jumps.push((c-n, cz+n));
+                        jumps.push((c-n, cz-n));
+                        jumps.push((c+n, cz+0));
+                        jumps.push((c-n, cz+0));
+                        jumps.push((c+0, cz+n));
+                        jumps.push((c+0, cz-n));
+                    }
+
+                    // Update coordinates
+                    lith.minx = lith.minx.min(x);
+                    lith.maxx = lith.maxx.max(x);
+                    lith.minz = lith.minz.min(z);
+                    lith.maxz = lith.maxz.max(z);
+                }
             }
-        }
 
-        Some(lith)
+            spill(queue);
+        }
     }
 
-    /// Get a Monolith at a given coordinate, compute properties
+    /// Walk from a cell in a direction while the next ones are unvisited members,
+    /// sampling rows of increasing length ahead. Returns the last member cell
     #[inline(always)]
-    #[cfg(feature="linear-fill")]
-    pub fn get_monolith(&self, x: i32, z: i32) -> Option<Monolith> {
+    fn flood_run(&self, grid: &mut impl FloodCells, mut c: i32, cz: i32, dir: i32, step: i32) -> i32 {
+        let mut members = [false; ROW_SAMPLES];
+        let mut ahead = 4;
+
+        loop {
+            // Visited cells would stop the run anyway
+            let mut n = 0;
+            while n < ahead && !grid.visited(c + dir*(n as i32 + 1), cz) {
+                n += 1;
+            }
+            if n == 0 {
+                return c;
+            }
 
-        // Most blocks are not monoliths
-        if !self.is_monolith(x, z) {
-            return None;
+            // Always sample rows along increasing x
+            let members = &mut members[..n];
+            if dir > 0 {
+                self.monolith_row((c+1)*step, cz*step, step, members);
+            } else {
+                self.monolith_row((c - n as i32)*step, cz*step, step, members);
+                members.reverse();
+            }
+
+            for &member in members.iter() {
+                if !grid.visit(c+dir, cz) || !member {
+                    return c;
+                }
+                c += dir;
+            }
+
+            ahead = (2*ahead).min(ROW_SAMPLES);
         }
+    }
 
-        let step = *AREA_STEP;
+    /// Expand a rectangle around a member block while its edges have any,
+    /// calling `runs` on each member cell. Note: Not filled on a torus,
+    /// only identified on it, and all of it is a single component
+    #[inline(always)]
+    fn linear_fill(&self, x: i32, z: i32, options: &FindOptions, runs: &impl Fn(&mut Monolith, i32, i32, i32)) -> Monolith {
+        let step = options.area_step;
         let cell = (step * step) as u64;
         let x = utils::nearest(x, step);
         let z = utils::nearest(z, step);
@@ -174,9 +633,15 @@ impl World {
         let mut lith = Monolith {
             minx: x, maxx: x,
             minz: z, maxz: z,
-            seed: self.seed,
+            id: match self.is_monolith(x, z) {
+                true  => (x, z),
+                false => NO_ID,
+            },
             area: cell,
+            components: 1,
+            ..self.monolith()
         };
+        runs(&mut lith, x/step, x/step, z/step);
 
         // Current search edge and how far to check
         let mut upto = [x-step, x+step, z-step, z+step];
@@ -195,17 +660,24 @@ impl World {
                 while edge[side] != upto[side] {
                     edge[side] += step * dir;
 
-                    let count = match axis {
+                    let members: Vec<(i32, i32)> = match axis {
                         0 => (edge[2]..=edge[3]).step_by(step as usize)
-                            .filter(|&z| self.is_monolith(edge[side], z))
-                            .count(),
+                            .map(|z| (edge[side], z))
+                            .filter(|&(x, z)| self.is_monolith(x, z))
+                            .collect(),
 
                         1 => (edge[0]..=edge[1]).step_by(step as usize)
-                            .filter(|&x| self.is_monolith(x, edge[side]))
-                            .count(),
+                            .map(|x| (x, edge[side]))
+                            .filter(|&(x, z)| self.is_monolith(x, z))
+                            .collect(),
 
                         _ => unreachable!(),
                     };
+                    let count = members.len();
+
+                    if let Some(&first) = members.iter().min() {
+                        lith.id = lith.id.min(first);
+                    }
 
                     if count == 0 {
                         break;
@@ -214,6 +686,9 @@ impl World {
 
                         // Speed: Single area operation
                         lith.area += cell * (count as u64);
+                        for &(x, z) in &members {
+                            runs(&mut lith, x/step, x/step, z/step);
+                        }
 
                         // Update search ranges
                         let next = edge[side] + (step * dir);
@@ -237,75 +712,176 @@ impl World {
         lith.minz = edge[2];
         lith.maxz = edge[3];
 
-        Some(lith)
+        lith
+    }
+
+    /// Whether any block within a rectangle could possibly be part of a monolith,
+    /// as told by bounds of the noises over it
+    #[inline(always)]
+    pub fn may_have_monolith(&self, minx: i32, maxx: i32, minz: i32, maxz: i32) -> bool {
+
+        // Can't tell where the depth noise coordinates overflow
+        if [minx, maxx, minz, maxz].iter().any(|v| v.unsigned_abs() >= DEPTH_LIMIT as u32) {
+            return true;
+        }
+
+        let hill = Rect {
+            minx: (minx >> 2) as f64, maxx: (maxx >> 2) as f64,
+            minz: (minz >> 2) as f64, maxz: (maxz >> 2) as f64,
+        };
+
+        if !self.hill.may_hill_monolith(&hill) {
+            return false;
+        }
+
+        let wide = (maxx as i64 - minx as i64).max(maxz as i64 - minz as i64) > DEPTH_BOUNDED;
+
+        if !S::ONLY_HILL && !wide {
+            let depth = Rect {
+                minx: (25 * minx) as f64, maxx: (25 * maxx) as f64,
+                minz: (25 * minz) as f64, maxz: (25 * maxz) as f64,
+            };
+            return self.depth.may_depth_monolith(&depth);
+        }
+
+        true
+    }
+
+    /// Monoliths within a search as they are found, each once, up to the limit
+    pub fn monoliths<'a>(&'a self, query: &'a FindOptions) -> impl Iterator<Item=Monolith> + 'a {
+        let mut found = AHashSet::new();
+
+        Probes::search(self, query)
+            .filter_map(|(x, z)| self.get_monolith(x, z, query))
+            .filter(|mono| mono.area > query.area)
+            .filter(move |mono| found.insert(mono.clone()))
+            .take(query.limit.map_or(usize::MAX, |many| many as usize))
+            .flat_map(|mono| self.copies(mono, query))
+    }
+
+    /// Same as `monoliths()` with strips of the search on all threads, and
+    /// large monoliths filled on all of them
+    pub fn par_monoliths<'a>(&'a self, query: &'a FindOptions) -> impl ParallelIterator<Item=Monolith> + 'a {
+        self.par_search(query, Probes::strips(query).into_par_iter())
+    }
+
+    fn par_search<'a>(&'a self,
+        query: &'a FindOptions,
+        strips: impl ParallelIterator<Item=Tile> + 'a,
+    ) -> impl ParallelIterator<Item=Monolith> + 'a {
+        let limit = query.limit.map_or(usize::MAX, |many| many as usize);
+        let found = Arc::new(Mutex::new(AHashSet::new()));
+        let done = Arc::new(AtomicBool::new(false));
+
+        strips.flat_map_iter(move |strip| {
+            let (found, done, stop) = (found.clone(), done.clone(), done.clone());
+
+            Probes::new(self, query, strip)
+                .take_while(move |_| !stop.load(Relaxed))
+                .filter_map(|(x, z)| self.par_get_monolith(x, z, query))
+                .filter(|mono| mono.area > query.area)
+                .filter(move |mono| {
+                    let mut found = found.lock().unwrap();
+                    let new = (found.len() < limit) && found.insert(mono.clone());
+
+                    // Stop all probing once the limit is reached
+                    if found.len() >= limit {
+                        done.store(true, Relaxed);
+                    }
+                    new
+                })
+        }).flat_map_iter(|mono| self.copies(mono, query))
     }
 
     #[inline(always)]
     pub fn find_monoliths(&self, query: &FindOptions) -> Vec<Monolith> {
-        let xrange: Vec<i32> = (query.minx..=query.maxx).step_by(query.step).collect();
-        let zrange: Vec<i32> = (query.minz..=query.maxz).step_by(query.step).collect();
 
         // Note: Lower latency, only use for huge areas
         if !query.threaded {
-            let mut monoliths = AHashSet::new();
-
-            'a: for x in &xrange {
-                for z in &zrange {
-                    if let Some(mono) = self.get_monolith(*x, *z) {
-                        if mono.area > query.area {
-                            monoliths.insert(mono);
-
-                            // Early break if limit is reached
-                            if let Some(many) = query.limit {
-                                if monoliths.len() >= many as usize {
-                                    break 'a;
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            return monoliths
-                .into_iter().collect();
+            return self.monoliths(query).collect();
+        }
 
         // Shred the cpu.
-        // Why bother breaking after a limit?
-        } else {
-            let monoliths = Arc::new(Mutex::new(AHashSet::new()));
-
-            // Nice to have an estimative of the progress yknow..
-            let progress = ProgressBar::new(xrange.len() as u64)
-                .with_style(utils::progress("Searching"));
-
-            xrange.clone()
-                .into_par_iter()
-                .progress_with(progress)
-                .for_each(|x| {
-                    for z in zrange.clone() {
-                        if let Some(mono) = self.get_monolith(x, z) {
-                            let mut monoliths = monoliths.lock().unwrap();
-                            monoliths.insert(mono);
-                        }
-                    }
-                });
+        let strips = Probes::strips(query);
+
+        // Nice to have an estimative of the progress yknow..
+        let progress = ProgressBar::new(strips.len() as u64)
+            .with_style(utils::progress("Searching"));
+
+        self.par_search(query, strips.into_par_iter().progress_with(progress))
+            .collect()
+    }
+
+    /// The monolith with the nearest block to a coordinate within a radius,
+    /// and how many blocks away it is, as far as probing nearest first can tell
+    pub fn nearest_monolith(&self, x: i32, z: i32, radius: i32) -> Option<(Monolith, f64)> {
+        let query = FindOptions::default().around(x, z, radius).nearest(true);
+        let slack = (query.step as f64) * std::f64::consts::SQRT_2;
+        let counting = Self::counting(&query, (0, 0));
+        let step = query.area_step;
+        let mut nearest: Option<(Monolith, f64)> = None;
+
+        // Runs of cells of the monoliths filled so far, by row
+        let filled: RefCell<AHashMap<i32, Vec<(i32, i32)>>> = RefCell::default();
+
+        for (px, pz) in Probes::search(self, &query) {
+            let probe = Self::run_distance((x, z), px, px, pz, 1);
+
+            // Monoliths missed by nearer probes are at most a probe apart
+            let bound = nearest.as_ref().map_or(radius as f64, |(_, edge)| *edge);
+            if probe > bound + slack {
+                break;
+            }
+
+            // Members of a monolith filled before would only fill it again
+            let (cx, cz) = ((px + step/2).div_euclid(step), (pz + step/2).div_euclid(step));
+            if filled.borrow().get(&cz).is_some_and(|runs| runs.iter().any(|&(l, r)| l <= cx && cx <= r)) {
+                continue;
+            }
+
+            let edge = Cell::new(probe);
+            let lith = self.fill(px, pz, &query, &|lith: &mut Monolith, l, r, cz| {
+                counting(lith, l, r, cz);
+                edge.set(edge.get().min(Self::run_distance((x, z), l, r, cz, step)));
+                filled.borrow_mut().entry(cz).or_default().push((l, r));
+            });
+
+            if lith.area > query.area && edge.get() <= bound
+                && nearest.as_ref().is_none_or(|(_, near)| edge.get() < *near) {
+                nearest = Some((self.finish(lith, &query), edge.get()));
+            }
+        }
+
+        nearest
+    }
+
+    /// Blocks from a coordinate to the nearest of a run of cells along x
+    fn run_distance((x, z): (i32, i32), l: i32, r: i32, cz: i32, step: i32) -> f64 {
+        let (x, z, step) = (x as i64, z as i64, step as i64);
+        let dx = (l as i64 * step - x).max(x - r as i64 * step).max(0);
+        let dz = cz as i64 * step - z;
+        (dx as f64).hypot(dz as f64)
+    }
 
-            return monoliths
-                .lock().unwrap().clone()
-                .into_iter().collect();
+    /// Monoliths found on a torus are all of their copies before the Far Lands
+    fn copies(&self, mono: Monolith, query: &FindOptions) -> Vec<Monolith> {
+        if !query.torus {
+            return vec![mono];
         }
+
+        mono.copies(S::TORUS_WRAPS).into_iter().filter_map(|mut copy| {
+            let shift = (copy.id.0 - mono.id.0, copy.id.1 - mono.id.1);
+            copy.inbounds = self.inbounds(&mono, query, shift);
+            copy.clip_farlands();
+            (copy.inbounds > 0).then_some(copy)
+        }).collect()
     }
 
     /// Lightweight alternative to `find_monoliths()`, shall only return one
     #[inline(always)]
     pub fn find_monolith(&self, query: &FindOptions) -> Option<Monolith> {
-        for x in (query.minx..=query.maxx).step_by(query.step) {
-            for z in (query.minz..=query.maxz).step_by(query.step) {
-                if let Some(mono) = self.get_monolith(x, z) {
-                    return Some(mono);
-                }
-            }
-        }
-        return None;
+        Probes::search(self, query)
+            .find_map(|(x, z)| self.get_monolith(x, z, query))
     }
 }
 
@@ -335,41 +911,32 @@ impl World {
     ///      - u =   3*(2**A - 1)/4    =~ 767.25
     ///      - s = sqrt((4**A - 1)/48) =~ 147.80
     ///
-    /// Such cutoffs can be tweaked with the QUALITY=x compile time variable.
+    /// Such cutoffs and the weights of each noise are tweaked with a `FractFilter`,
+    /// and the depth noise included with the strategy's `depth_fracts`.
     ///
     #[inline(always)]
-    pub fn good_perlin_fracts(seed: Seed) -> bool {
+    pub fn good_perlin_fracts(seed: Seed, strategy: &Strategy, filter: &FractFilter) -> bool {
         let mut rng: JavaRNG;
 
-        if cfg!(feature="state-seed") {
+        if strategy.state_seed {
             rng = JavaRNG::from_state(seed);
         } else {
             rng = JavaRNG::from_seed(seed);
-            Perlin::discard(&mut rng, SKIP_OCTAVES);
-        };
-
-        // Shorthand to get quality var or default
-        let kwality = |default: f64| {
-            option_env!("QUALITY")
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(default)
+            Perlin::discard(&mut rng, SKIP_OCTAVES, strategy.rejection());
         };
 
         // Heuristic numbers to filter out 'bad' seeds
-        let (quality, noises): (f64, &[usize]);
-        if cfg!(feature="depth-fracts") {
-            noises  = &[HILL_OCTAVES, DEPTH_OCTAVES];
-            quality = kwality(28000.0);
-        } else {
-            noises  = &[HILL_OCTAVES];
-            quality = kwality(280.0);
+        let quality = filter.quality(strategy.depth_fracts);
+        let noises: &[(usize, f64)] = match strategy.depth_fracts {
+            true  => &[(HILL_OCTAVES, filter.hill_weight), (DEPTH_OCTAVES, filter.depth_weight)],
+            false => &[(HILL_OCTAVES, filter.hill_weight)],
         };
 
         // Simulate offsets rng
         let mut deviate = 0.0;
-        for part in noises {
-            for octave in 0..(*part) {
-                let scale = FractalPerlin::<0>::octave_scale_mul_f64(octave);
+        for &(part, weight) in noises {
+            for octave in 0..part {
+                let scale = weight * FractalPerlin::<0>::octave_scale_mul_f64(octave);
 
                 for _ in 0..3 {
                     let next = rng.next_f64_256();
@@ -392,63 +959,61 @@ impl World {
 /* -------------------------------------------------------------------------- */
 // Sister perlins
 
-impl World {
+impl<S: Sampling> World<S> {
 
     /// Generate a sister-world where the LCG state "started" at the end of ours
     /// first perlin noise generation. Effectively, roll down all the current
     /// noise octaves, generate and append a new one at the end.
     #[inline(always)]
-    #[cfg(feature="only-hill")]
     pub fn sister_perlin(&mut self) {
-        // Hill: 0123456789 -> into 123456789(New)
+        let rejection = self.strategy.rejection();
         self.hill.noise.rotate_left(1);
-        self.hill.noise[HILL_OCTAVES - 1] = Perlin::from_rng(&mut self.rng);
-        self.seed = self.seed_from_state();
-    }
 
-    /// Generate a sister-world where the LCG state "started" at the end of ours
-    /// first perlin noise generation. Effectively, roll down all the current
-    /// noise octaves, generate and append a new one at the end.
-    #[inline(always)]
-    #[cfg(not(feature="only-hill"))]
-    pub fn sister_perlin(&mut self) {
+        // Hill: 0123456789 -> into 123456789(New)
+        if S::ONLY_HILL {
+            self.hill.noise[HILL_OCTAVES - 1] = LazyPerlin::from_rng(&mut self.rng, rejection);
+
         // Hill: 0123456789 Depth: ABCD... -> into
         // Hill: 123456789A Depth: BCDE...(New)
-        self.hill.noise.rotate_left(1);
-        self.hill.noise[HILL_OCTAVES - 1] = self.depth.noise[0].clone();
-        self.depth.noise.rotate_left(1);
-        self.depth.noise[DEPTH_OCTAVES - 1] = Perlin::from_rng(&mut self.rng);
-        self.seed = self.seed_from_state();
+        } else {
+            self.hill.noise[HILL_OCTAVES - 1] = self.depth.noise[0].clone();
+            self.depth.noise.rotate_left(1);
+            self.depth.noise[DEPTH_OCTAVES - 1] = LazyPerlin::from_rng(&mut self.rng, rejection);
+        }
+        self.seed = self.guess_seed();
     }
 
-    /// Get a seed from the current RNG state
+    /// Octaves the generator rolled from the seed to the current state
+    const OCTAVES: usize =
+        if S::ONLY_HILL {0} else {DEPTH_OCTAVES}
+      + HILL_OCTAVES
+      + SKIP_OCTAVES;
+
+    /// Get a seed from the current RNG state, assuming no shuffle rejected
+    /// a draw, otherwise `seed_from_state()` recovers the real one
     #[inline(always)]
-    #[cfg(feature="only-hill")]
-    pub fn seed_from_state(&self) -> Seed {
-        let mut rev = self.rng.clone();
-        Perlin::undiscard(&mut rev,
-            HILL_OCTAVES
-          + SKIP_OCTAVES
-        );
+    pub fn guess_seed(&self) -> Seed {
+        let mut rev = self.rng;
+        Perlin::undiscard(&mut rev, Self::OCTAVES);
         return rev.reverse_seed();
     }
 
-    #[inline(always)]
-    #[cfg(not(feature="only-hill"))]
-    pub fn seed_from_state(&self) -> Seed {
-        let mut rev = self.rng.clone();
-        Perlin::undiscard(&mut rev,
-            DEPTH_OCTAVES
-          + HILL_OCTAVES
-          + SKIP_OCTAVES
-        );
-        return rev.reverse_seed();
+    /// Get the real seed from the current RNG state, much slower than
+    /// `guess_seed()`, so only worth it for worlds being reported. None
+    /// when no seed leads exactly to this state
+    pub fn seed_from_state(&self) -> Option<Seed> {
+        let mut rev = self.rng;
+        match Perlin::undiscard_exact(&mut rev, Self::OCTAVES, self.strategy.rejection()) {
+            true  => Some(rev.reverse_seed()),
+            false => None,
+        }
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-#[derive(SmartDefault)]
+#[derive(Clone, Debug, PartialEq, SmartDefault, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FindOptions {
     pub minx: i32,
     pub maxx: i32,
@@ -467,6 +1032,37 @@ pub struct FindOptions {
 
     /// Whether to use multithreading
     pub threaded: bool,
+
+    /// Fill monoliths on the repeating world, finding each once
+    pub torus: bool,
+
+    /// Probe outwards from the centre, finding nearer monoliths first
+    pub nearest: bool,
+
+    /// Only probe blocks within this region of the rectangle
+    pub region: Option<Region>,
+
+    /// Cells joined by edges only (4) or also by corners (8)
+    #[default(4)]
+    pub connectivity: u8,
+
+    /// Blocks away that pieces still merge as satellites of a monolith, none at 0.
+    /// Note: Only then is the starting cell counted, satellite searches claim it first
+    #[default(128)]
+    pub satellites: i32,
+
+    /// Blocks between member cells updating coordinates and looking for satellites
+    #[default(32)]
+    pub stride: i32,
+
+    /// Step by 4 blocks when calculating areas, as each noise coordinate is the world
+    /// position divided by 4. This causes a small error in area calculation due to
+    /// interpolation, but the average of over/under-shoots gives at most 1% error.
+    ///
+    /// - Must be a power of two, so the cells tile the repeating worlds.
+    ///
+    #[default(4)]
+    pub area_step: i32,
 }
 
 impl FindOptions {
@@ -491,14 +1087,69 @@ impl FindOptions {
         return self;
     }
 
+    pub fn torus(mut self, value: bool) -> Self {
+        self.torus = value;
+        return self;
+    }
+
+    pub fn nearest(mut self, value: bool) -> Self {
+        self.nearest = value;
+        return self;
+    }
+
+    pub fn connectivity(mut self, value: u8) -> Self {
+        assert!(value == 4 || value == 8, "Connectivity must be 4 or 8");
+        self.connectivity = value;
+        return self;
+    }
+
+    pub fn satellites(mut self, blocks: i32) -> Self {
+        self.satellites = blocks.max(0);
+        return self;
+    }
+
+    pub fn stride(mut self, blocks: i32) -> Self {
+        self.stride = blocks;
+        return self;
+    }
+
+    pub fn area_step(mut self, blocks: i32) -> Self {
+        assert!(blocks > 0 && (blocks as u32).is_power_of_two(), "Area step must be a power of two");
+        self.area_step = blocks;
+        return self;
+    }
+
+    /// Block at the middle of the region
+    pub fn centre(&self) -> (i64, i64) {
+        (
+            (self.minx as i64 + self.maxx as i64).div_euclid(2),
+            (self.minz as i64 + self.maxz as i64).div_euclid(2),
+        )
+    }
+
     // Defining regions
 
+    /// Search only within a region's shapes, or the current rectangle without
+    /// its exclusions when it includes none
+    pub fn region(mut self, mut region: Region) -> Self {
+        if region.include.is_empty() {
+            region.include.push(Shape::Rect {
+                minx: self.minx, maxx: self.maxx,
+                minz: self.minz, maxz: self.maxz,
+            });
+        }
+        (self.minx, self.maxx, self.minz, self.maxz) = region.bounds();
+        self.region = Some(region);
+        return self;
+    }
+
     /// Search around a given coordinate at most `radius` manhattan blocks away
     pub fn around(mut self, x: i32, z: i32, radius: i32) -> Self {
-        self.minx = x - radius;
-        self.maxx = x + radius;
-        self.minz = z - radius;
-        self.maxz = z + radius;
+        let radius = radius.saturating_abs();
+        self.minx = x.saturating_sub(radius);
+        self.maxx = x.saturating_add(radius);
+        self.minz = z.saturating_sub(radius);
+        self.maxz = z.saturating_add(radius);
         return self;
     }
 
@@ -516,21 +1167,428 @@ impl FindOptions {
         return self;
     }
 
-    /// Search all blocks within hill noise wrap distance
-    pub fn hill_wraps(mut self) -> Self {
+    /// Search all blocks within hill noise wrap distance, on a torus when
+    /// that's all monoliths there are, as they are with only the hill noise
+    pub fn hill_wraps(mut self, only_hill: bool) -> Self {
         self.minx = 0;
         self.maxx = HILL_WRAPS;
         self.minz = 0;
         self.maxz = HILL_WRAPS;
+        self.torus = only_hill;
         return self;
     }
 
-    /// Search all blocks within depth noise wrap distance
+    /// Search all blocks within depth noise wrap distance, on a torus
     pub fn depth_wraps(mut self) -> Self {
         self.minx = 0;
         self.maxx = DEPTH_WRAPS;
         self.minz = 0;
         self.maxz = DEPTH_WRAPS;
+        self.torus = true;
         return self;
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+#[cfg(not(feature="state-seed"))]
+mod tests {
+    use crate::*;
+
+    /// Worlds initialized in lanes must match the scalar ones, also when reused
+    #[test]
+    fn init_lanes() {
+        let mut lanes: [World; 4] = std::array::from_fn(|_| World::new());
+
+        for seeds in [[617, 256846, 419981, 432938], [0, 140740248931398, 105561095840220, 35192371993854]] {
+            World::init_lanes(&mut lanes, seeds);
+
+            for (lane, seed) in seeds.into_iter().enumerate() {
+                let mut world = World::new();
+                world.init(seed);
+                assert_eq!(lanes[lane].rng, world.rng);
+                assert_eq!(lanes[lane].hill.sample(1234.0, -567.0), world.hill.sample(1234.0, -567.0));
+
+                #[cfg(not(feature="only-hill"))]
+                assert_eq!(lanes[lane].depth.sample(-89.0, 1011.0), world.depth.sample(-89.0, 1011.0));
+            }
+        }
+    }
+
+    /// The depth noise is only shuffled past the hill check, with the generator
+    /// rolled past it as if it was
+    #[test]
+    #[cfg(not(feature="only-hill"))]
+    fn lazy_depth() {
+        let mut world = World::new();
+        world.init(617);
+
+        let rejection = world.strategy.rejection();
+        let mut rng = JavaRNG::from_seed(617);
+        Perlin::discard(&mut rng, SKIP_OCTAVES + HILL_OCTAVES + DEPTH_OCTAVES, rejection);
+        assert_eq!(world.rng, rng);
+
+        assert!(!world.is_monolith(0, 0));
+        assert!(!world.hill.is_hill_monolith(0, 0));
+        assert!(world.depth.noise.iter().all(|octave| !octave.shuffled()));
+
+        assert!(world.is_monolith(16080, 9424));
+        assert!(world.depth.noise.iter().any(|octave| octave.shuffled()));
+    }
+
+    /// Octaves shuffled on first use must match the ones generated in sequence
+    #[test]
+    fn lazy_octaves() {
+        for seed in [617, 256846, 419981] {
+            let mut world = World::new();
+            world.init(seed);
+
+            let rejection = world.strategy.rejection();
+            let mut rng = JavaRNG::from_seed(seed);
+            Perlin::discard(&mut rng, SKIP_OCTAVES, rejection);
+
+            for octave in 0..HILL_OCTAVES {
+                let perlin = Perlin::from_rng(&mut rng, rejection);
+                assert_eq!(world.hill.noise[octave].map, perlin.map);
+                assert_eq!(world.hill.noise[octave].xoff, perlin.xoff);
+            }
+        }
+    }
+
+    /// Noise bounds over rectangles must hold all samples within them, and
+    /// agree with their early exits
+    #[test]
+    fn bounds_over() {
+        let mut world = World::new();
+        world.init(617);
+        let mut rng = JavaRNG::from_seed(42);
+
+        for _ in 0..500 {
+            let minx = rng.next_f64() * 4000.0 - 2000.0;
+            let minz = rng.next_f64() * 4000.0 - 2000.0;
+            let size = rng.next_f64() * 200.0;
+            let rect = Rect {minx, maxx: minx + size, minz, maxz: minz + size};
+            let (lo, hi) = world.hill.bounds_over(&rect);
+
+            assert_eq!(world.hill.may_hill_monolith(&rect), lo < HILL_TARGET);
+
+            #[cfg(not(feature="only-hill"))]
+            let depth = world.depth.bounds_over(&rect.scale(25.0));
+
+            #[cfg(not(feature="only-hill"))]
+            assert_eq!(world.depth.may_depth_monolith(&rect.scale(25.0)),
+                depth.0 < -DEPTH_TARGET || depth.1 > DEPTH_TARGET);
+
+            for _ in 0..20 {
+                let x = minx + rng.next_f64() * size;
+                let z = minz + rng.next_f64() * size;
+                let value = world.hill.sample(x, z);
+                assert!(lo <= value && value <= hi);
+
+                #[cfg(not(feature="only-hill"))] {
+                    let value = world.depth.sample(25.0*x, 25.0*z);
+                    assert!(depth.0 <= value && value <= depth.1);
+                }
+            }
+        }
+    }
+
+    /// Depth noise bounds prove narrow rectangles where the hill ones can't
+    #[test]
+    #[cfg(not(feature="only-hill"))]
+    fn depth_bounds() {
+        let mut world = World::new();
+        world.init(617);
+        let mut proven = 0;
+
+        for x in (15000..17000).step_by(16) {
+            for z in (8500..10500).step_by(16) {
+                let hill = Rect {
+                    minx: (x >> 2) as f64, maxx: ((x + 15) >> 2) as f64,
+                    minz: (z >> 2) as f64, maxz: ((z + 15) >> 2) as f64,
+                };
+                if world.hill.bounds_over(&hill).0 < HILL_TARGET && !world.may_have_monolith(x, x + 15, z, z + 15) {
+                    proven += 1;
+                }
+            }
+        }
+        assert!(proven > 0);
+    }
+
+    /// Streamed searches find the same monoliths, and stop at the limit
+    #[test]
+    fn monoliths() {
+        let mut world = World::new();
+        world.init(617);
+        let query = FindOptions::default().spawn(20000).step(64);
+
+        let found: Vec<Monolith> = world.monoliths(&query).collect();
+        let par: AHashSet<Monolith> = world.par_monoliths(&query).collect::<Vec<_>>().into_iter().collect();
+        assert!(found.len() > 3);
+        assert_eq!(found.len(), par.len());
+        assert_eq!(found.iter().cloned().collect::<AHashSet<_>>(), par);
+
+        let query = query.limit(3);
+        assert_eq!(world.monoliths(&query).collect::<Vec<_>>(), found[..3]);
+        assert_eq!(world.par_monoliths(&query).count(), 3);
+    }
+
+    /// Satellites add whole components, corners only ever join more cells
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn flood_options() {
+        let mut world = World::new();
+        world.init(617);
+
+        for (x, z) in [(16080, 9424), (-18608, -15024)] {
+            let complex = FindOptions::default();
+            let blob = FindOptions::default().satellites(0);
+            let corners = FindOptions::default().satellites(0).connectivity(8);
+            let [complex, blob, corners] = [complex, blob, corners].map(|query| {
+                let mono = world.get_monolith(x, z, &query).unwrap();
+                let par = world.par_get_monolith(x, z, &query).unwrap();
+                assert_eq!((mono.area, mono.components), (par.area, par.components));
+                mono
+            });
+
+            assert!(complex.components >= 1);
+            assert_eq!((blob.components, corners.components), (1, 1));
+            assert!(blob.area <= corners.area);
+
+            // Note: The starting cell of a complex is never counted
+            let step = FindOptions::default().area_step;
+            assert!(blob.area <= complex.area + (step * step) as u64);
+        }
+
+        // Both start from the cell nearest to the block, also below zero
+        let query = FindOptions::default().area_step(4);
+        let (_, start, _) = world.flood_start(-5, -7, &query);
+        let (_, _, cells) = world.flood_start(-5, -7, &query.clone().satellites(0));
+        assert_eq!((start, cells), (Some((-1, -2)), vec![(-1, -2)]));
+
+        // Far satellites merge more pieces into a complex
+        let near = world.get_monolith(16080, 9424, &FindOptions::default()).unwrap();
+        let far = world.get_monolith(16080, 9424, &FindOptions::default().satellites(1024)).unwrap();
+        assert!(far.area >= near.area && far.components >= near.components);
+
+        let par = world.par_get_monolith(16080, 9424, &FindOptions::default().satellites(1024)).unwrap();
+        assert_eq!((far.area, far.components), (par.area, par.components));
+    }
+
+    /// Coarser area steps only sample the same monolith less accurately
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn area_step() {
+        let mut world = World::new();
+        world.init(617);
+
+        for step in [2, 8, 16, 64] {
+            let query = FindOptions::default().area_step(step);
+            let mono = world.get_monolith(16080, 9424, &query).unwrap();
+            let error = if step > 32 {10} else {20};
+            assert!(mono.area.abs_diff(169552) < 169552/error, "{step} {}", mono.area);
+            assert_eq!((mono.id.0 % step, mono.id.1 % step), (0, 0));
+        }
+    }
+
+    /// Stricter or more weighted fract filters only keep fewer seeds
+    #[test]
+    fn fract_filter() {
+        let strategy = Strategy::default();
+        let good = |filter: FractFilter| (0..2000)
+            .filter(|&seed| World::good_perlin_fracts(seed, &strategy, &filter))
+            .count();
+
+        let default = good(FractFilter::default());
+        assert!(0 < default && default < 2000);
+        assert!(good(FractFilter {quality: Some(200.0), ..Default::default()}) < default);
+        assert!(good(FractFilter {hill_weight: 2.0, ..Default::default()}) < default);
+        assert_eq!(good(FractFilter {hill_weight: 0.0, ..Default::default()}), 2000);
+    }
+
+    /// Probing outwards yields nearer members first, down to a probe apart
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn nearest() {
+        let mut world = World::new();
+        world.init(617);
+        let query = FindOptions::default().around(16000, 9000, 5000).step(64).nearest(true);
+
+        let centre = query.centre();
+        let distances: Vec<f64> = Probes::search(&world, &query)
+            .map(|(x, z)| ((x as i64 - centre.0) as f64).hypot((z as i64 - centre.1) as f64))
+            .collect();
+        assert!(!distances.is_empty());
+        assert!(distances.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        let (inside, edge) = world.nearest_monolith(16080, 9424, 20000).unwrap();
+        assert_eq!(edge, 0.0);
+        assert_eq!((inside.minx, inside.minz), (15968, 9344));
+
+        let (outside, edge) = world.nearest_monolith(15000, 9000, 20000).unwrap();
+        assert_eq!(outside, inside);
+        assert!(edge > 900.0 && edge < 1100.0);
+    }
+
+    /// Pruned searches must find the same monoliths as probing everything
+    #[test]
+    fn find_monoliths() {
+        let mut world = World::new();
+        world.init(617);
+        let query = FindOptions::default().spawn(20000).step(64);
+
+        let pruned: AHashSet<Monolith> = world.find_monoliths(&query).into_iter().collect();
+        let mut every = AHashSet::new();
+        for x in (query.minx..=query.maxx).step_by(query.step) {
+            for z in (query.minz..=query.maxz).step_by(query.step) {
+                every.extend(world.get_monolith(x, z, &query).filter(|mono| mono.area > 0));
+            }
+        }
+
+        assert!(!every.is_empty());
+        assert_eq!(pruned, every);
+    }
+
+    /// Rows of samples must match each point's early exit checks
+    #[test]
+    fn monolith_row() {
+        let mut world = World::new();
+        world.init(617);
+        let mut row = [false; 400];
+
+        for z in (9344..10240).step_by(36) {
+            for (x0, step) in [(15000, 4), (15001, 8), (-1998, 12), (15968, 2)] {
+                world.monolith_row(x0, z, step, &mut row);
+                for (i, &member) in row.iter().enumerate() {
+                    assert_eq!(member, world.is_monolith(x0 + (i as i32)*step, z));
+                }
+
+                // Rows of one noise past ROW_SAMPLES too, on whole hill coordinates
+                if step % 4 != 0 {
+                    continue;
+                }
+                world.hill.is_hill_monolith_row(x0, z, step, &mut row);
+                for (i, &member) in row.iter().enumerate() {
+                    assert_eq!(member, world.hill.is_hill_monolith(x0 + (i as i32)*step, z));
+                }
+            }
+        }
+
+        // Depth rows past the Far Lands wrap like each point does
+        let far = 100_000_000;
+        world.depth.is_depth_monolith_row(far, -far, 4, &mut row);
+        for (i, &member) in row.iter().enumerate() {
+            assert_eq!(member, world.depth.is_depth_monolith(far + (i as i32)*4, -far));
+        }
+    }
+
+    /// Flood fills must keep finding the same monoliths
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn get_monolith() {
+        let query = FindOptions::default();
+        for (seed, x, z, area, minx, maxx, minz, maxz) in [
+            (617,    16080,   9424, 169552, 15968, 16384,   9344,  10240),
+            (617,   -18608, -15024,  50496, -18656, -18496, -15360, -14944),
+            (256846, -7984,   3536,  52032, -8032, -7744,    3360,   3648),
+            (256846, 23120, -23600,  33968, 23104, 23296,  -23744, -23424),
+        ] {
+            let mut world = World::new();
+            world.init(seed);
+            for mono in [world.get_monolith(x, z, &query), world.par_get_monolith(x, z, &query)] {
+                let mono = mono.unwrap();
+                assert_eq!(mono.area, area);
+                assert_eq!((mono.minx, mono.maxx, mono.minz, mono.maxz), (minx, maxx, minz, maxz));
+            }
+        }
+    }
+
+    /// Any block of a monolith identifies it the same way
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn monolith_id() {
+        let query = FindOptions::default();
+        let mut world = World::new();
+        world.init(617);
+        let mono = world.get_monolith(16080, 9424, &query).unwrap();
+
+        for x in (mono.minx..=mono.maxx).step_by(64) {
+            for z in (mono.minz..=mono.maxz).step_by(64) {
+                if let Some(other) = world.get_monolith(x, z, &query) {
+                    assert_eq!(other.id, mono.id);
+                    assert_eq!(other, mono);
+                }
+            }
+        }
+
+        // Not the same monolith in another world
+        assert_ne!(Monolith {seed: 618, ..mono.clone()}, mono);
+    }
+
+    /// Monoliths are the same on all repeats of the world
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn torus() {
+        let query = FindOptions::default().torus(true);
+        let mut world = World::new();
+        world.init(617);
+
+        let mono = world.get_monolith(16080, 9424, &query).unwrap();
+        assert_eq!(mono.area, 169552);
+        let wraps = Compiled::TORUS_WRAPS;
+        assert!((0..wraps).contains(&mono.id.0));
+        assert!((0..wraps).contains(&mono.id.1));
+
+        for (x, z) in [(16080 + wraps, 9424), (16080 + wraps, 9424 + wraps)] {
+            let other = world.par_get_monolith(x, z, &query).unwrap();
+            assert_eq!((other.area, other.id), (mono.area, mono.id));
+            assert_eq!((other.minx, other.minz), (mono.minx, mono.minz));
+        }
+
+        // Three repeats on each axis before the Far Lands
+        let copies = mono.copies(wraps);
+        assert_eq!(copies.len(), 9);
+        assert_eq!(copies.iter().collect::<AHashSet<_>>().len(), 9);
+        assert!(copies.iter().all(|copy| copy.minx >= -FARLANDS && copy.maxx <= FARLANDS));
+    }
+
+    /// Copies past the Far Lands only count the blocks before them
+    #[test]
+    #[cfg(not(any(feature="linear-fill", feature="only-hill")))]
+    fn farlands() {
+        let query = FindOptions::default();
+        let mut world = World::new();
+        world.init(617);
+
+        let mono = world.get_monolith(16080, 9424, &query).unwrap();
+        assert_eq!((mono.inbounds, mono.clipped), (mono.area, false));
+        assert_eq!(mono.farlands, FARLANDS - 16384);
+
+        // Cut by one edge where the other one would resume it
+        let area = world.fill(mono.id.0, mono.id.1, &query, &World::<Compiled>::counting(&query, (0, 0))).area;
+        let east = world.inbounds(&mono, &query, (FARLANDS - 16200, 0));
+        let west = world.inbounds(&mono, &query, (-FARLANDS - 16201, 0));
+        assert!(0 < east && east < area);
+        assert_eq!(east + west, area);
+
+        // Regions near the limits saturate
+        let query = FindOptions::default().around(i32::MAX - 5, i32::MIN + 5, 100);
+        assert_eq!((query.maxx, query.minz), (i32::MAX, i32::MIN));
+    }
+
+    /// Seeds whose skipped octaves rejects values must still be recovered,
+    /// while guesses are only right for the others
+    #[test]
+    fn seed_from_state() {
+        for (seed, rejects) in [
+            (0, false), (617, false), (256846, true),
+            (419981, true), (140740248931398, true), (94116384388573, false),
+        ] {
+            let mut world = World::new();
+            world.init(seed);
+            assert_eq!(world.seed_from_state(), Some(seed));
+            assert_eq!(world.guess_seed() != seed, rejects);
+        }
+    }
+}
@@ -3,10 +3,10 @@ import json
 import sys
 from abc import ABC, abstractmethod
 from subprocess import PIPE
-from typing import Iterable
+from typing import Iterable, Union
 
 import numpy
-from attrs import Factory, define
+from attrs import Factory, define, field
 
 from monolithium import rustlith
 
@@ -30,13 +30,28 @@ class Monolith:
     minz: int
     maxz: int
 
+    # Smallest member block, the same wherever it was found from
+    id: tuple[int, int] = field(default=(0, 0), converter=tuple)
+
+    # Area before the Far Lands, whether they overwrite some, blocks to them
+    inbounds: int = 0
+    clipped: bool = False
+    farlands: int = 0
+
+    # Connected pieces merged into it as satellites
+    components: int = 0
+
+    # Signed or text seed as written, when not the same number
+    user_seed: Union[int, str, None] = None
+
     # Note: Area is approximate, not on hash
     def __hash__(self) -> int:
-        return hash((
-            self.seed,
-            self.minx, self.maxx,
-            self.minz, self.maxz,
-        ))
+        return hash((self.seed, self.id))
+
+    def __eq__(self, other: object) -> bool:
+        if not isinstance(other, Monolith):
+            return NotImplemented
+        return (self.seed, self.id) == (other.seed, other.id)
 
     @property
     def cenx(self) -> int: